@@ -2,7 +2,7 @@ use ggez::{graphics::Color, *};
 use mooeye::{scene_manager, ui, ui::UiContainer, ui::UiContent};
 
 // # Containers
-// In this example, we learn about the 4 main types of containers provided with mooeye and use them to create a UI containing multiple elements.
+// In this example, we learn about the main types of containers provided with mooeye and use them to create a UI containing multiple elements.
 
 /// A very basic scene struct, once again only holding the root element of our GUI.
 pub struct DScene {
@@ -64,8 +64,10 @@ impl DScene {
                 .set_font("Bahnschrift")
                 .set_scale(28.)
                 .to_owned()
-                .to_element_builder(0, ctx)
+                .to_element_builder(10 + i, ctx)
                 .with_visuals(vis2)
+                // Elements can be made draggable by giving them a payload of your message type (here, simply ()).
+                .with_drag_payload(())
                 .build();
             // Add the element to the box. This cannot fail for non-grid containers, if ver_box were not an actual container
             // or a container that requires a special method for adding, like e.g. GridBox, it would simply consume the element and do nothing.
@@ -121,6 +123,8 @@ impl DScene {
                 .with_alignment(ui::Alignment::Min, ui::Alignment::Min)
                 // and offset it slightly
                 .with_offset(-10., -10.)
+                // Only the topmost element below the mouse reacts to clicks. As the icon is purely decorative, we let the mouse pass through it to the button below.
+                .with_pass_through(true)
                 .build(),
         )?;
         // to_element is a shorthand for to_element_builder().build() if we want to simply take the default builder and not change anything.
@@ -136,16 +140,53 @@ impl DScene {
             .with_padding((24., 16., 16., 16.))
             .build();
 
+        // If a container has more children than fit on the screen, we can wrap it in a ScrollBox.
+        // The scroll box displays its child at full size, but only shows the part currently visible through the box.
+        let mut log_box = ui::containers::VerticalBox::new();
+        for i in 0..20 {
+            log_box.add(
+                graphics::Text::new(format!("Log entry {}", i))
+                    .set_font("Bahnschrift")
+                    .set_scale(20.)
+                    .to_owned()
+                    .to_element(0, ctx),
+            );
+        }
+        // The log box accepts dragged elements. When something is dropped onto it, we receive a DragDropped message (see below).
+        let log_box = log_box
+            .to_element_builder(3, ctx)
+            .with_drop_target(|_| true)
+            .with_drop_visuals(hover_vis)
+            .build();
+        let scroll = ui::containers::ScrollBox::new(log_box)
+            .to_element_builder(0, ctx)
+            .with_visuals(cont_vis)
+            .with_padding((24., 16., 16., 16.))
+            // The scroll box can be made as small as we want, so we need to give it a size.
+            .with_size(None, ui::Size::Fixed(200.))
+            .build();
+
         // The horizontal box is exactly the same as the vertical box except for orientation.
         // We will use a horizontal box to contain the boxes created so far.
-        // if you don't want to create multiple variables, adding of children can be done inline for non-grid
-        // containers by using .with_child.
+        // Instead of creating multiple variables, nested trees can also be written declaratively with the ui! macro.
+        // It expands to the same builder calls as above: properties like 'visuals = vis' become '.with_visuals(vis)'.
+        // Elements built beforehand are embedded with element(...), and the macro returns a Result as loading images or filling grids can fail.
         Ok(Self {
-            gui: ui::containers::HorizontalBox::new()
-                .to_element_builder(0, ctx)
-                .with_child(ver_box)
-                .with_child(grid)
-                .build(),
+            gui: mooeye::ui!(ctx, hbox() {
+                element(ver_box),
+                element(grid),
+                vbox(spacing = 10.) {
+                    element(scroll),
+                    content(
+                        graphics::Text::new("Drag numbers into the log.")
+                            .set_font("Bahnschrift")
+                            .set_scale(20.)
+                            .to_owned(),
+                        visuals = vis,
+                        padding = (4., 8., 4., 8.),
+                    ),
+                },
+            })?,
         })
     }
 }
@@ -161,6 +202,15 @@ impl scene_manager::Scene for DScene {
             return Ok(scene_manager::SceneSwitch::pop(1));
         }
 
+        // Dropping an element does not move it automatically, so we move it from the vertical box into the log box ourselves.
+        for message in messages {
+            if let ui::UiMessage::DragDropped { source, target: 3, .. } = message {
+                if let Some(element) = self.gui.take_element(source) {
+                    self.gui.add_element(3, element);
+                }
+            }
+        }
+
         Ok(scene_manager::SceneSwitch::None)
     }
 
@@ -177,4 +227,10 @@ impl scene_manager::Scene for DScene {
 
         Ok(())
     }
+
+    fn mouse_wheel_event(&mut self, ctx: &mut Context, x: f32, y: f32) -> Result<(), GameError> {
+        // Mouse wheel events are not stored in the context, so we have to pass them on to our GUI for the scroll box to work.
+        self.gui.mouse_wheel_event(ctx, x, y);
+        Ok(())
+    }
 }
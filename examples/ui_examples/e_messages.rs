@@ -44,8 +44,10 @@ impl EScene {
                         // If yes, we add a new transition to the vector.
                         transitions.push_back(
                             // Transitions are initalized with the duration they should take to complete and augmented via the builder pattern.
-                            ui::Transition::new(Duration::ZERO)
+                            ui::Transition::new(Duration::from_secs_f32(0.3))
                             // Here, we add a new content that will replace the old text once the transition completes.
+                            // With cross-fading, the new text fades in over the duration of the transition while the old one fades out.
+                            .with_crossfade(true)
                             .with_new_content(Text::new(format!(
                                 "Move this element with the buttons.\nYou clicked a button with id {}.",
                                 id
@@ -56,6 +58,19 @@ impl EScene {
                         )
                         
                     }
+                    // Elements also send messages when the mouse starts (or stops) hovering above them, is dragged, etc.
+                    if *message == ui::UiMessage::<()>::HoverStart(id){
+                        transitions.push_back(
+                            ui::Transition::new(Duration::ZERO)
+                            .with_new_content(Text::new(format!(
+                                "Move this element with the buttons.\nYou are hovering above the button with id {}.",
+                                id
+                            ))
+                            .set_font("Bahnschrift")
+                            .set_scale(24.)
+                            .to_owned())
+                        )
+                    }
                 }
             }
         })
@@ -69,6 +84,15 @@ impl EScene {
             4.,
         );
 
+        // Buttons can also be focused and triggered with the keyboard only. Tab and the arrow keys move the focus, Enter or Space trigger the focused button.
+        // Focus visuals are displayed to show the player which button is currently focused.
+        let focus_vis = ui::Visuals::new(
+            Color::from_rgb(77, 109, 191),
+            Color::from_rgb(220, 220, 240),
+            3.,
+            4.,
+        );
+
         // Create a grid box to hold all buttons.
         let mut grid_box = ui::containers::GridBox::new(2, 3);
 
@@ -77,9 +101,28 @@ impl EScene {
             .set_font("Bahnschrift")
             .to_owned()
             .to_element_builder(11, ctx)
-            .with_visuals(vis) 
+            .with_visuals(vis)
+            .with_focusable(true)
+            .with_focus_visuals(focus_vis)
             // We can also set a sound to be played on click/key press
             .with_trigger_sound(ggez::audio::Source::new(ctx, "/blipSelect.wav").ok())
+            // Transforms scale, rotate and move an element without changing the layout of it or its neighbours.
+            // Here, the button shrinks quickly when triggered and then pops back to its original size.
+            .with_message_handler(|messages, _, transitions| {
+                if messages.contains(&ui::UiMessage::Triggered(11)) {
+                    // Clearing the queue first replaces a pop still running, so rapid clicks don't queue up.
+                    transitions.clear();
+                    transitions.push_back(
+                        ui::Transition::new(Duration::from_secs_f32(0.1))
+                        .with_new_transform(ui::UiTransform::new().with_scale(0.8))
+                    );
+                    transitions.push_back(
+                        ui::Transition::new(Duration::from_secs_f32(0.4))
+                        .with_easing(ui::Easing::BackOut)
+                        .with_new_transform(ui::UiTransform::new())
+                    );
+                }
+            })
             .build();
         grid_box
             .add(vert_up, 0, 0)?;
@@ -89,6 +132,8 @@ impl EScene {
             .to_owned()
             .to_element_builder(12, ctx)
             .with_visuals(vis)
+            .with_focusable(true)
+            .with_focus_visuals(focus_vis)
             .build();
         grid_box
             .add(vert_ce, 0, 1)?;
@@ -97,6 +142,8 @@ impl EScene {
             .set_font("Bahnschrift")
             .to_owned().to_element_builder(13, ctx)
             .with_visuals(vis)
+            .with_focusable(true)
+            .with_focus_visuals(focus_vis)
             .build();
         grid_box
             .add(vert_do, 0, 2)?;
@@ -105,6 +152,8 @@ impl EScene {
             .set_font("Bahnschrift")
             .to_owned().to_element_builder(21, ctx)
             .with_visuals(vis)
+            .with_focusable(true)
+            .with_focus_visuals(focus_vis)
             .build();
         grid_box
             .add(hor_up, 1, 0)?;
@@ -113,6 +162,8 @@ impl EScene {
             .set_font("Bahnschrift")
             .to_owned().to_element_builder(22, ctx)
             .with_visuals(vis)
+            .with_focusable(true)
+            .with_focus_visuals(focus_vis)
             .build();
         grid_box
             .add(hor_ce, 1, 1)?;
@@ -121,6 +172,8 @@ impl EScene {
             .set_font("Bahnschrift")
             .to_owned().to_element_builder(23, ctx)
             .with_visuals(vis)
+            .with_focusable(true)
+            .with_focus_visuals(focus_vis)
             .build();
         grid_box
             .add(hor_do, 1, 2)?;
@@ -131,17 +184,44 @@ impl EScene {
             .to_owned()
             .to_element_builder(1, ctx)
             .with_visuals(vis)
+            .with_focusable(true)
+            .with_focus_visuals(focus_vis)
+            // Marking this as a back button lets the east button of a gamepad (B on most controllers) take us back as well.
+            .with_back(true)
+            // Animations play continuously along a list of keyframes, independently of any transitions.
+            // This one makes the back button pulse until the mouse hovers above it.
+            .with_animation(
+                ui::Animation::new()
+                .with_keyframe(ui::Keyframe::new(Duration::ZERO).with_opacity(1.))
+                .with_keyframe(ui::Keyframe::new(Duration::from_secs_f32(0.8)).with_opacity(0.6).with_easing(ui::Easing::QuadInOut))
+                .with_mode(ui::AnimationMode::PingPong)
+                .with_stop_message(ui::UiMessage::HoverStart(1))
+                .with_play_message(ui::UiMessage::HoverEnd(1))
+            )
             .as_fill()
             .build();
 
+        // A text input lets the user type. It sends TextChanged on every edit and TextSubmitted when enter is pressed.
+        // Note that the scene has to forward text input and key events to the GUI for this to work (see below).
+        let input = ui::basic::TextInput::new()
+            .with_placeholder("Type & press enter")
+            .with_font("Bahnschrift")
+            .with_scale(20.)
+            .to_element_builder(2, ctx)
+            .with_visuals(vis)
+            // Text inputs are focusable by default. Triggering a focused text input lets the player type into it.
+            .with_focus_visuals(focus_vis)
+            .build();
+
         
         // We create a general VBox to contain our UI
         // We can create Vertical and Horizontal Boxes with the 'spaced' constructor to set its spacing value.
-        let gui_box = ui::containers::VerticalBox::new_spaced(6.)
+        let mut gui_box = ui::containers::VerticalBox::new_spaced(6.)
         .to_element_builder(0, ctx)
         // We put the title, grid and back button together in a box.
         .with_child(title)
         .with_child(grid_box.to_element(30, ctx))
+        .with_child(input)
         .with_child(back)
         .with_size(ui::Size::Shrink(128., f32::INFINITY), ui::Size::Shrink(0., f32::INFINITY))
         .with_visuals(ui::Visuals::new(
@@ -155,7 +235,8 @@ impl EScene {
         // the relevant parts of the layout without having to recreate it from scratch.
         .with_message_handler(|messages, layout, transitions| {
             // This guards prevent spam clicking a button from locking up the element with 1.5-second transitions.
-            if !transitions.is_empty() {
+            // Only transitions changing the layout are relevant here, as transitions on other channels (like the fade-in below) run in parallel.
+            if transitions.iter().any(|transition| transition.affects(ui::TransitionChannel::Layout)) {
                 return;
             }
             let vert_map = HashMap::from([
@@ -174,6 +255,8 @@ impl EScene {
                     transitions.push_back(
                         // If yes, add a transition.
                         ui::Transition::new(Duration::from_secs_f32(1.5))
+                        // Easing curves make the movement start and end smoothly instead of moving at constant speed.
+                        .with_easing(ui::Easing::CubicInOut)
                         // This time, we don't change the content, but the layout.
                         // Layout, visuals and hover_visuals are not  changed on completion like content, but instead applied gradually.
                         .with_new_layout(ui::Layout{
@@ -187,7 +270,7 @@ impl EScene {
             for (key, val) in hor_map {
                 if messages.contains(&ui::UiMessage::Triggered(key)) {
                     transitions.push_back(
-                        ui::Transition::new(Duration::from_secs_f32(1.5)).with_new_layout(ui::Layout{
+                        ui::Transition::new(Duration::from_secs_f32(1.5)).with_easing(ui::Easing::BackOut).with_new_layout(ui::Layout{
                             x_alignment: val,
                             ..layout
                         }),
@@ -195,17 +278,28 @@ impl EScene {
                 }
             }
         })
+        // The box starts out invisible. Opacity (just like tint) is passed on to all children.
+        .with_opacity(0.)
         .build();
 
+        // Transitions can also be added directly. This one fades in the box and all its children.
+        gui_box.add_transition(
+            ui::Transition::new(Duration::from_secs_f32(0.5))
+            .with_new_opacity(1.)
+        );
+
         // Finally, we wrap our gui_box into a space-filling stack pane so we have a place to later add further elements
 
-        Ok(Self {
-            gui: ui::containers::StackBox::new()
+        let mut gui = ui::containers::StackBox::new()
             .to_element_builder(100, ctx)
             .as_fill()
             .with_child(gui_box)
-            .build()
-        })
+            .build();
+
+        // We start with the first button focused, so the player can start navigating right away.
+        gui.set_focus(11);
+
+        Ok(Self { gui })
     }
 }
 
@@ -217,7 +311,17 @@ impl scene_manager::Scene for EScene {
         let messages = self.gui.manage_messages(ctx, None);
 
         if messages.contains(&ui::UiMessage::Triggered(1)){
-            // If it is, we end the current scene (and return to the previous one) by popping it off the stack.
+            // If it is, we first fade out the whole GUI. The transition sends a message once it completes.
+            self.gui.clear_transitions();
+            self.gui.add_transition(
+                ui::Transition::new(Duration::from_secs_f32(0.3))
+                .with_new_opacity(0.)
+                .with_completion_message(())
+            );
+        }
+
+        if messages.contains(&ui::UiMessage::Extern(())){
+            // When the fade-out is complete, we end the current scene (and return to the previous one) by popping it off the stack.
             return Ok(scene_manager::SceneSwitch::pop(1));
         }
 
@@ -248,10 +352,58 @@ impl scene_manager::Scene for EScene {
                     );
         }
 
+        if messages.contains(&ui::UiMessage::TextSubmitted(2)){
+            // The submitted text can be read from the GUI by the ID of the input element.
+            let text = self.gui.get_text(2).unwrap_or_default().to_owned();
+            // Elements can also be found by their ID and changed directly, here we clear the text field after submitting.
+            if let Some(input) = self.gui.find_mut(2).and_then(|element| element.content_as_mut::<ui::basic::TextInput>()){
+                input.set_text("");
+            }
+            self.gui.add_element(100,
+                ui::containers::DurationBox::new(
+                    Duration::from_secs_f32(1.5),
+                    graphics::Text::new(format!("You wrote: {}", text))
+                    .set_font("Bahnschrift")
+                    .set_scale(28.)
+                    .to_owned()
+                    .to_element(0, ctx)
+                ).to_element_builder(0, ctx)
+                .with_alignment(ui::Alignment::Center, ui::Alignment::Max)
+                .with_offset(0., -25.)
+                .build()
+            );
+        }
+
         Ok(scene_manager::SceneSwitch::None)
     
     }
 
+    fn text_input_event(&mut self, _ctx: &mut Context, character: char) -> Result<(), GameError> {
+        // Text input is not stored in the context, so we pass it on to our GUI.
+        self.gui.text_input_event(character);
+        Ok(())
+    }
+
+    fn key_down_event(&mut self, ctx: &mut Context, input: input::keyboard::KeyInput, _repeated: bool) -> Result<(), GameError> {
+        // Keys like backspace and the arrow keys are also passed on. If the GUI did not use the key, we quit on escape just like the default implementation.
+        if !self.gui.key_down_event(input) && input.keycode == Some(input::keyboard::KeyCode::Escape) {
+            ctx.request_quit();
+        }
+        Ok(())
+    }
+
+    fn gamepad_button_down_event(&mut self, _ctx: &mut Context, btn: event::Button, _id: event::GamepadId) -> Result<(), GameError> {
+        // Gamepad events are passed on as well, allowing the player to move the focus with the D-pad and trigger buttons with the south button.
+        self.gui.gamepad_button_down_event(btn);
+        Ok(())
+    }
+
+    fn gamepad_axis_event(&mut self, _ctx: &mut Context, axis: event::Axis, value: f32, _id: event::GamepadId) -> Result<(), GameError> {
+        // The left stick can also be used to move the focus.
+        self.gui.gamepad_axis_event(axis, value);
+        Ok(())
+    }
+
     fn draw(&mut self, ctx: &mut Context, mouse_listen: bool) -> Result<(), GameError> {
 
         // Once again the basic drawing function.
@@ -1,5 +1,5 @@
 use ggez::{graphics::Color, *};
-use mooeye::{scene_manager, ui, ui::UiContent};
+use mooeye::{scene_manager, ui};
 
 /// This scene is the main-drop in scene and allows you to access the different tutorial scenes.
 /// This is not intended to be a tutorial in itself and is thus more sparsely commented,
@@ -8,6 +8,12 @@ pub struct SelectorScene {
     /// The root element of this scene's GUI.
     /// As this is just a UI-scene with no underlying game state, no further fields are neccessary.
     gui: ui::UiElement<()>,
+    /// The two themes the GUI can be displayed in. Pressing T switches between them.
+    themes: [ui::Theme; 2],
+    /// The index of the theme currently applied.
+    current_theme: usize,
+    /// Watches the description file of the GUI and rebuilds it on changes.
+    watcher: ui::UiWatcher,
 }
 
 impl SelectorScene {
@@ -15,79 +21,83 @@ impl SelectorScene {
     /// This is the initial drop-in scene and it is always the same, so with the exception of the context
     /// required for creating text and image elements no further parameters are neccessary.
     pub fn new(ctx: &Context) -> Result<Self, GameError> {
-        // Defining visuals
-
-        let vis = ui::Visuals::new(
-            Color::from_rgb(180, 120, 60),
-            Color::from_rgb(18, 12, 6),
-            1.,
-            0.,
-        );
-
-        let hover_vis = ui::Visuals::new(
-            Color::from_rgb(160, 100, 40),
-            Color::from_rgb(18, 12, 6),
-            3.,
-            0.,
-        );
-
-        // Creating main grid
-
-        let mut grid = ui::containers::GridBox::new(3, 2);
-
-        let contents = ["Scene", "UiElement", "Container", "Messages", "Sprites"];
-
-        for (i, &cont) in contents.iter().enumerate() {
-            grid.add(
-                graphics::Text::new(cont)
-                    .set_scale(32.)
-                    .to_owned()
-                    .to_element_builder(i as u32 + 1, ctx)
-                    .with_visuals(vis)
-                    .with_hover_visuals(hover_vis)
-                    .with_tooltip(
-                        graphics::Text::new(format!(
-                            "Click to look at the Scene created in the file {}.",
-                            contents[i].to_lowercase()
-                        ))
-                        .set_scale(24.)
-                        .set_wrap(true)
-                        .set_bounds(glam::Vec2::new(240., 500.))
-                        .to_owned()
-                        .to_element_builder(0, ctx)
-                        .with_visuals(hover_vis)
-                        .build(),
-                    )
-                    .build(),
-                i % 3,
-                i / 3,
-            )?;
-        }
-
-        // Add quit button
-
-        grid.add(
-            graphics::Text::new("Quit")
-                .set_scale(32.)
-                .to_owned()
-                .to_element_builder(6, ctx)
-                .with_visuals(vis)
-                .with_hover_visuals(hover_vis)
-                .build(),
-            2,
-            1,
-        )?;
+        // Defining themes. Elements only name their style classes, the theme decides what they look like.
+
+        let themes = [
+            Self::theme(
+                Color::from_rgb(180, 120, 60),
+                Color::from_rgb(160, 100, 40),
+                Color::from_rgb(18, 12, 6),
+            ),
+            Self::theme(
+                Color::from_rgb(40, 44, 60),
+                Color::from_rgb(60, 66, 90),
+                Color::from_rgb(200, 200, 230),
+            ),
+        ];
+
+        // Loading the main grid from a description file. The watcher reloads it whenever the file changes,
+        // so the layout can be edited while the game is running.
+
+        let mut watcher = ui::UiWatcher::new("/selector.json");
+        let mut gui = watcher.load(ctx, &ui::UiLoader::new())?;
+        gui.apply_theme(ctx, &themes[0]);
 
         Ok(Self {
-            gui: grid.to_element_builder(0, ctx).build(),
+            gui,
+            themes,
+            current_theme: 0,
+            watcher,
         })
     }
+
+    /// Creates a theme with the passed background, hover background and text/border colors.
+    fn theme(background: Color, hover: Color, foreground: Color) -> ui::Theme {
+        let vis = ui::Visuals::new(background, foreground, 1., 0.);
+        let hover_vis = ui::Visuals::new(hover, foreground, 3., 0.);
+
+        ui::Theme::new()
+            .with_class(
+                "button",
+                ui::Style::new()
+                    .with_visuals(vis)
+                    .with_hover_visuals(hover_vis)
+                    .with_text_scale(32.),
+            )
+            .with_class(
+                "tooltip",
+                ui::Style::new()
+                    .with_visuals(hover_vis)
+                    .with_text_scale(24.),
+            )
+    }
 }
 
 impl scene_manager::Scene for SelectorScene {
     fn update(&mut self, ctx: &mut Context) -> Result<scene_manager::SceneSwitch, GameError> {
+        // Hot reloading the GUI. A broken description keeps the previous GUI alive.
+
+        match self.watcher.poll(ctx, &ui::UiLoader::new()) {
+            Some(Ok(gui)) => {
+                self.gui = gui;
+                self.gui.apply_theme(ctx, &self.themes[self.current_theme]);
+            }
+            Some(Err(error)) => println!("[ERROR] {}", error),
+            None => {}
+        }
+
         let messages = self.gui.manage_messages(ctx, None);
 
+        // Switching themes re-skins the entire GUI
+
+        if ctx
+            .keyboard
+            .is_key_just_pressed(winit::event::VirtualKeyCode::T)
+        {
+            self.current_theme = (self.current_theme + 1) % self.themes.len();
+            self.gui.apply_theme(ctx, &self.themes[self.current_theme]);
+        }
+
         // Scene switches for different scenes
 
         if messages.contains(&ui::UiMessage::Triggered(1)) {
@@ -32,7 +32,7 @@ mod c_uielement;
 
 /// ## D: Containers
 ///
-/// In this example, we learn about the 4 main types of containers provided with mooeye and use them to create a UI containing multiple elements.
+/// In this example, we learn about the main types of containers provided with mooeye and use them to create a UI containing multiple elements.
 mod d_containers;
 
 /// ## E: Messages & Transitions
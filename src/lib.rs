@@ -24,3 +24,7 @@ pub mod scene_manager;
 pub mod sprite;
 /// Contains the main components for creating UIs.
 pub mod ui;
+
+/// Re-exported for use within the [ui!] macro.
+#[doc(hidden)]
+pub use ggez;
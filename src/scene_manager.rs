@@ -88,6 +88,58 @@ impl event::EventHandler<GameError> for SceneManager {
 
         Ok(())
     }
+
+    fn mouse_wheel_event(&mut self, ctx: &mut Context, x: f32, y: f32) -> Result<(), GameError> {
+        // only the top scene receives input events, just as only the top scene runs update
+        if let Some(scene) = self.scene_stack.back_mut() {
+            scene.mouse_wheel_event(ctx, x, y)?;
+        }
+        Ok(())
+    }
+
+    fn text_input_event(&mut self, ctx: &mut Context, character: char) -> Result<(), GameError> {
+        if let Some(scene) = self.scene_stack.back_mut() {
+            scene.text_input_event(ctx, character)?;
+        }
+        Ok(())
+    }
+
+    fn key_down_event(
+        &mut self,
+        ctx: &mut Context,
+        input: input::keyboard::KeyInput,
+        repeated: bool,
+    ) -> Result<(), GameError> {
+        if let Some(scene) = self.scene_stack.back_mut() {
+            scene.key_down_event(ctx, input, repeated)?;
+        }
+        Ok(())
+    }
+
+    fn gamepad_button_down_event(
+        &mut self,
+        ctx: &mut Context,
+        btn: event::Button,
+        id: event::GamepadId,
+    ) -> Result<(), GameError> {
+        if let Some(scene) = self.scene_stack.back_mut() {
+            scene.gamepad_button_down_event(ctx, btn, id)?;
+        }
+        Ok(())
+    }
+
+    fn gamepad_axis_event(
+        &mut self,
+        ctx: &mut Context,
+        axis: event::Axis,
+        value: f32,
+        id: event::GamepadId,
+    ) -> Result<(), GameError> {
+        if let Some(scene) = self.scene_stack.back_mut() {
+            scene.gamepad_axis_event(ctx, axis, value, id)?;
+        }
+        Ok(())
+    }
 }
 
 /// A SceneSwitch. An element of this type is returned from every scene every frame to check if the scene wants to switch to another scene.
@@ -133,4 +185,58 @@ pub trait Scene {
     /// In general, you should NOT clear the background when drawing your scene, as it may be on top of other scenes that also need to be drawn.
     /// If you want those scenes to remain hidden, clear the background.
     fn draw(&mut self, ctx: &mut Context, mouse_listen: bool) -> Result<(), GameError>;
+
+    /// A function that fulfils the same purpose as [ggez::event::EventHandler::mouse_wheel_event]. Only called on the top scene.
+    /// Forward the event to your UI (see [crate::ui::UiElement::mouse_wheel_event]) if it contains scrollable elements.
+    /// Default implementation ignores the event.
+    fn mouse_wheel_event(&mut self, _ctx: &mut Context, _x: f32, _y: f32) -> Result<(), GameError> {
+        Ok(())
+    }
+
+    /// A function that fulfils the same purpose as [ggez::event::EventHandler::text_input_event]. Only called on the top scene.
+    /// Forward the event to your UI (see [crate::ui::UiElement::text_input_event]) if it contains text fields.
+    /// Default implementation ignores the event.
+    fn text_input_event(&mut self, _ctx: &mut Context, _character: char) -> Result<(), GameError> {
+        Ok(())
+    }
+
+    /// A function that fulfils the same purpose as [ggez::event::EventHandler::key_down_event]. Only called on the top scene.
+    /// Forward the event to your UI (see [crate::ui::UiElement::key_down_event]) if it contains text fields.
+    /// Default implementation mirrors the one of ggez and quits the game when escape is pressed.
+    fn key_down_event(
+        &mut self,
+        ctx: &mut Context,
+        input: input::keyboard::KeyInput,
+        _repeated: bool,
+    ) -> Result<(), GameError> {
+        if input.keycode == Some(input::keyboard::KeyCode::Escape) {
+            ctx.request_quit();
+        }
+        Ok(())
+    }
+
+    /// A function that fulfils the same purpose as [ggez::event::EventHandler::gamepad_button_down_event]. Only called on the top scene.
+    /// Forward the event to your UI (see [crate::ui::UiElement::gamepad_button_down_event]) to make it usable with a gamepad.
+    /// Default implementation ignores the event.
+    fn gamepad_button_down_event(
+        &mut self,
+        _ctx: &mut Context,
+        _btn: event::Button,
+        _id: event::GamepadId,
+    ) -> Result<(), GameError> {
+        Ok(())
+    }
+
+    /// A function that fulfils the same purpose as [ggez::event::EventHandler::gamepad_axis_event]. Only called on the top scene.
+    /// Forward the event to your UI (see [crate::ui::UiElement::gamepad_axis_event]) to make it navigable with a gamepad stick.
+    /// Default implementation ignores the event.
+    fn gamepad_axis_event(
+        &mut self,
+        _ctx: &mut Context,
+        _axis: event::Axis,
+        _value: f32,
+        _id: event::GamepadId,
+    ) -> Result<(), GameError> {
+        Ok(())
+    }
 }
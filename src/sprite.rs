@@ -134,17 +134,15 @@ impl Sprite {
     }
 
     /// Sets the variant this sprite is currently displaying. Numbers that are too large to represent a valid variant will wrap around.
+    /// Sprites without a spritesheet (or with a spritesheet smaller than a single frame) keep the passed number.
     pub fn set_variant(&mut self, variant: u32) {
         if self.current_variant != variant {
-            self.current_variant = variant;
-            if self.h != 0 {
-                self.current_variant %= self
-                    .spritesheet
-                    .as_ref()
-                    .map(|img| img.height())
-                    .unwrap_or_default()
-                    / self.h;
-            }
+            self.current_variant = self
+                .spritesheet
+                .as_ref()
+                .and_then(|img| img.height().checked_div(self.h))
+                .and_then(|variants| variant.checked_rem(variants))
+                .unwrap_or(variant);
             self.current_frame_time = Duration::ZERO;
             self.current_frame = 0;
         }
@@ -248,16 +246,26 @@ impl<T: Copy + Eq + Hash> UiContent<T> for Sprite {
         canvas: &mut graphics::Canvas,
         param: crate::ui::UiDrawParam,
     ) {
-        self.draw_sprite(
-            ctx,
-            canvas,
-            param.param.dest_rect(Rect::new(
-                param.target.x,
-                param.target.y,
-                param.target.w / self.w as f32,
-                param.target.h / self.h as f32,
-            )),
-        );
+        param.draw_clipped(canvas, |canvas| {
+            self.draw_sprite(
+                ctx,
+                canvas,
+                param.param.dest_rect(Rect::new(
+                    param.target.x,
+                    param.target.y,
+                    param.target.w / self.w as f32,
+                    param.target.h / self.h as f32,
+                )),
+            );
+        });
+    }
+
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
+
+    fn as_any_mut(&mut self) -> Option<&mut dyn std::any::Any> {
+        Some(self)
     }
 }
 
@@ -1,11 +1,14 @@
 use std::collections::{HashSet, VecDeque};
 use std::hash::Hash;
+use std::time::Duration;
 
 use ggez::audio::{SoundSource, Source};
+use ggez::event::Button;
+use ggez::input::keyboard::KeyInput;
 use ggez::winit::event::VirtualKeyCode;
 use ggez::{
-    glam::Vec2,
-    graphics::{Canvas, Rect},
+    glam::{Affine2, Mat4, Vec2},
+    graphics::{Canvas, Color, Rect},
     Context, GameResult,
 };
 
@@ -15,8 +18,8 @@ pub use ui_element::UiContainer;
 pub use ui_element::UiContent;
 
 /// Contains basic UI contents such as text and images.
-/// There is nothing actually here, because the basic elements Text, Image and Empty
-/// are created by simply implementing UiContent on ggez's Text and Image as well as the basic ().
+/// Most basic elements (Text, Image and Empty) are created by simply implementing UiContent on ggez's Text and Image as well as the basic ().
+/// The only struct actually contained here is the editable [basic::TextInput].
 pub mod basic;
 /// Contains UI contents that contain other UI elements, such as vertical boxes and stack boxes.
 pub mod containers;
@@ -27,27 +30,92 @@ pub use layout::Alignment;
 pub use layout::Layout;
 pub use layout::Size;
 
+/// The [LayoutTree] struct and the [Measure] trait to compute the layout of elements without drawing them or requiring a ggez Context.
+mod layout_tree;
+pub use layout_tree::LayoutTree;
+pub use layout_tree::Measure;
+pub use layout_tree::MonospaceMeasure;
+
+/// Functions to print [LayoutTree]s in a stable text format and compare them against stored snapshots in tests.
+mod snapshot;
+pub use snapshot::UPDATE_SNAPSHOTS_VAR;
+
 /// The [Visuals] structs as well as associated functions that control how an element looks.
 mod visuals;
 use tinyvec::TinyVec;
 pub use visuals::Visuals;
 
+/// The [Easing] enum to control the speed of transitions over their duration.
+mod easing;
+pub use easing::Easing;
+
+/// The [UiTransform] struct to scale, rotate and move elements without changing their layout.
+mod ui_transform;
+pub use ui_transform::UiTransform;
+
+/// The [Animation] struct and its [Keyframe]s to continuously change an element independently of its transitions.
+mod animation;
+pub use animation::Animation;
+pub use animation::AnimationMode;
+pub use animation::Keyframe;
+
 /// The [Transition] struct and associated functions to control an element dynamically changing layout, visuals, content, etc.
 mod transition;
 pub use transition::Transition;
+pub use transition::TransitionChannel;
+
+/// The [HandlerContext] struct that gives mutable message handlers access to their element.
+mod handler_context;
+pub use handler_context::HandlerContext;
 
 /// The [DrawCache] struct to remember where an element was drawn in the last frame and (if possible) simply redraw it without recalculating its position.
 mod draw_cache;
 use draw_cache::DrawCache;
 
+/// Functions to manage which element holds the keyboard focus and to move the focus between elements using keyboard or gamepad.
+mod focus;
+
+/// Functions to find the topmost element below the mouse cursor, so that only this element reacts to mouse input.
+mod hit_test;
+
+/// Functions to drag elements carrying a payload and drop them onto other elements.
+mod drag_drop;
+
+/// The [MouseState] struct to remember how the mouse interacted with an element across frames.
+mod mouse_state;
+
+/// The [InputState] struct to pass the mouse, keyboard and timer state of a frame to [UiElement::update_with_input].
+mod input_state;
+pub use input_state::InputState;
+
+/// The [UiHarness] struct to simulate input and frames on an element without a window, e.g. in tests.
+mod harness;
+pub use harness::UiHarness;
+use mouse_state::MouseState;
+
+/// The [Theme] struct containing named [Style] classes to style many elements at once and re-skin them at runtime.
+mod theme;
+pub use theme::Style;
+pub use theme::Theme;
+
+/// The [UiLoader] and [UiWatcher] structs to create element trees from declarative descriptions and reload them when the description changes.
+mod loader;
+pub use loader::LoadError;
+pub use loader::UiLoader;
+pub use loader::UiWatcher;
+
 /// The [UiMessage] struct to facilitate communcation between elements and between elements an the game state.
 mod message;
 pub use message::UiMessage;
+pub use message::UiValue;
 
 /// The [UiElementBuilder] struct for simple construction of UiElements using a basic builder pattern.
 mod ui_element_builder;
 pub use ui_element_builder::UiElementBuilder;
 
+/// The [crate::ui!] macro to declaratively build trees of UiElements.
+mod dsl;
+
 /// The [UiDrawParam] struct is an extension of the [ggez::graphics::DrawParam] struct and contains some additonal information specific to UiElements.
 mod ui_draw_param;
 pub use ui_draw_param::UiDrawParam;
@@ -61,8 +129,22 @@ pub struct UiElement<T: Copy + Eq + Hash> {
     visuals: Visuals,
     /// The alternative visuals of this element, displayed while the user hovers the mouse cursor above it.
     hover_visuals: Option<Visuals>,
+    /// The alternative visuals of this element, displayed while it holds the keyboard focus.
+    focus_visuals: Option<Visuals>,
+    /// The opacity of this element and its children, multiplied with the opacity of its parent.
+    opacity: f32,
+    /// The color this element and its children are tinted with, multiplied with the tint of its parent.
+    tint: Color,
+    /// The scaling, rotation and movement applied to this element and its children when drawn.
+    transform: UiTransform,
     /// The sound that is played whenever the element is triggered via mouse or key press.
     trigger_sound: Option<Source>,
+    /// The names of the [Theme] style classes applied to this element, in the order they are applied.
+    classes: Vec<String>,
+    /// The values of the fields set by themes before the first theme was applied to this element. None if no theme was applied yet.
+    unthemed: Option<theme::Unthemed>,
+    /// The sound set by the current theme, played instead of the trigger sound of this element.
+    theme_trigger_sound: Option<Source>,
 
     /// The elements ID. Not neccessarily guaranteed to be unique.
     id: u32,
@@ -70,6 +152,33 @@ pub struct UiElement<T: Copy + Eq + Hash> {
     /// This elements draw cache.
     draw_cache: DrawCache,
 
+    /// This elements mouse state, tracking hovering, dragging, etc.
+    mouse_state: MouseState,
+
+    /// Wether this element or one of its successors is the topmost element below the mouse cursor.
+    mouse_hit: bool,
+
+    /// Wether this element is the innermost element with an ID other than 0 below the mouse cursor, and thus receives mouse input.
+    mouse_target: bool,
+
+    /// The mouse position (in screen coordinates) during the last update. Dragged elements and tooltips follow this position while being drawn.
+    mouse_position: Vec2,
+
+    /// Wether this element lets the mouse pass through to the elements below it.
+    pass_through: bool,
+
+    /// The payload carried by this element if it can be dragged.
+    drag_payload: Option<T>,
+
+    /// The predicate deciding which payloads this element accepts, if it is a drop target.
+    drop_filter: Option<DropFilter<T>>,
+
+    /// The alternative visuals of this element, displayed while an element with an accepted payload is dragged above it.
+    drop_visuals: Option<Visuals>,
+
+    /// Wether an element with an accepted payload is currently dragged above this element.
+    drop_hovered: bool,
+
     /// The conent managed & displayed by this element
     pub content: Box<dyn UiContent<T>>,
 
@@ -79,18 +188,57 @@ pub struct UiElement<T: Copy + Eq + Hash> {
     /// The transition queue
     transitions: VecDeque<Transition<T>>,
 
+    /// The messages caused by completed transitions, sent during the next message collection.
+    transition_messages: Vec<UiMessage<T>>,
+
+    /// The messages emitted by the message handler, distributed within the current update or (if too many rounds of messages were emitted) during the next message collection.
+    emitted_messages: Vec<UiMessage<T>>,
+
+    /// Wether the content was accessed mutably via [UiElement::content_as_mut] and needs to be measured again before the element is drawn next.
+    measure_pending: bool,
+
+    /// The animation continuously playing on this element, if it has one.
+    animation: Option<Animation<T>>,
+
     /// The keyboard key triggering events on this element.
     keys: TinyVec<[Option<VirtualKeyCode>; 2]>,
 
+    /// The gamepad buttons triggering events on this element.
+    gamepad_buttons: TinyVec<[Option<Button>; 2]>,
+
+    /// Wether this element was triggered by a gamepad button since the last message collection.
+    gamepad_triggered: bool,
+
+    /// Wether this element is a back or cancel button, triggered by the east button of a gamepad.
+    back: bool,
+
+    /// The last position of the gamepad stick received by this element. Only used on the element gamepad events are passed to, usually the root.
+    stick_position: Vec2,
+
+    /// Wether this element can receive the keyboard focus. Only has an effect for elements with an ID other than 0.
+    focusable: bool,
+
+    /// Wether this element currently holds the keyboard focus.
+    focused: bool,
+
+    /// Wether the content (and thus all children) of this element are clipped to the outer bounds of this element.
+    clip_children: bool,
+
     /// The message handler. This function is called on every frame to handle received message.
     /// The message handler lambda receives each frame a hash set consisting of all internal and external messages received by this element.
-    /// It also receives a function pointer. Calling this pointer with a transition pushes that transition to this elements transition queue.
-    /// Lastly, it receives the current layout of the element. This allows any transitions to re-use that layout and only change the variables the transition wants to change.
+    /// It also receives a [HandlerContext] to change this element, queue transitions and emit messages.
+    /// Handlers set via [UiElementBuilder::with_message_handler] are wrapped to only use the layout and transition queue of the context.
     message_handler: MessageHandler<T>,
 }
 
+/// The maximum number of times messages emitted by message handlers are distributed within a single update, so handlers reacting to each others messages cannot loop forever.
+const MAX_MESSAGE_ROUNDS: usize = 16;
+
+/// The functional type of a UiElements drop target predicate.
+type DropFilter<T> = Box<dyn Fn(&T) -> bool>;
+
 /// The functional type of a UiElements MessageHandler.
-type MessageHandler<T> = Box<dyn Fn(&HashSet<UiMessage<T>>, Layout, &mut VecDeque<Transition<T>>)>;
+type MessageHandler<T> = Box<dyn FnMut(&HashSet<UiMessage<T>>, &mut HandlerContext<T>)>;
 
 impl<T: Copy + Eq + Hash> std::fmt::Debug for UiElement<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -98,11 +246,27 @@ impl<T: Copy + Eq + Hash> std::fmt::Debug for UiElement<T> {
             .field("layout", &self.layout)
             .field("visuals", &self.visuals)
             .field("hover_visuals", &self.hover_visuals)
+            .field("focus_visuals", &self.focus_visuals)
+            .field("opacity", &self.opacity)
+            .field("tint", &self.tint)
+            .field("transform", &self.transform)
             .field("trigger_sound", &self.trigger_sound)
+            .field("classes", &self.classes)
+            .field("unthemed", &self.unthemed)
+            .field("theme_trigger_sound", &self.theme_trigger_sound)
             .field("id", &self.id)
             .field("draw_cache", &self.draw_cache)
+            .field("mouse_state", &self.mouse_state)
+            .field("pass_through", &self.pass_through)
+            .field("drop_visuals", &self.drop_visuals)
             .field("tooltip", &self.tooltip)
+            .field("animation", &self.animation)
             .field("keys", &self.keys)
+            .field("gamepad_buttons", &self.gamepad_buttons)
+            .field("back", &self.back)
+            .field("focusable", &self.focusable)
+            .field("focused", &self.focused)
+            .field("clip_children", &self.clip_children)
             .finish()
     }
 }
@@ -117,14 +281,41 @@ impl<T: Copy + Eq + Hash> UiElement<T> {
             layout: Layout::default(),
             visuals: Visuals::default(),
             hover_visuals: None,
+            focus_visuals: None,
+            opacity: 1.,
+            tint: Color::WHITE,
+            transform: UiTransform::default(),
             trigger_sound: None,
+            classes: Vec::new(),
+            unthemed: None,
+            theme_trigger_sound: None,
             id,
             draw_cache: DrawCache::default(),
+            mouse_state: MouseState::default(),
+            mouse_hit: false,
+            mouse_target: false,
+            mouse_position: Vec2::ZERO,
+            pass_through: false,
+            drag_payload: None,
+            drop_filter: None,
+            drop_visuals: None,
+            drop_hovered: false,
             content: Box::new(content),
             tooltip: None,
             transitions: VecDeque::new(),
+            transition_messages: Vec::new(),
+            emitted_messages: Vec::new(),
+            measure_pending: false,
+            animation: None,
             keys: TinyVec::new(),
-            message_handler: Box::new(|_messages, _layout, _transition_queue| {}),
+            gamepad_buttons: TinyVec::new(),
+            gamepad_triggered: false,
+            back: false,
+            stick_position: Vec2::ZERO,
+            focusable: false,
+            focused: false,
+            clip_children: false,
+            message_handler: Box::new(|_messages, _context| {}),
         }
     }
 
@@ -167,6 +358,153 @@ impl<T: Copy + Eq + Hash> UiElement<T> {
         }
     }
 
+    /// Removes the first element with the given ID from this element or (recursively) its children and returns it.
+    /// Returns None if no such element was found (or it is the child of a container that does not support taking children).
+    /// Together with [UiElement::add_element], this allows moving an element within the UI, e.g. after a [UiMessage::DragDropped].
+    pub fn take_element(&mut self, id: u32) -> Option<UiElement<T>> {
+        let cont = self.content.container_mut()?;
+        if let Some(element) = cont.take_id(id) {
+            return Some(element);
+        }
+        cont.get_children_mut()
+            .iter_mut()
+            .find_map(|child| child.take_element(id))
+    }
+
+    /// Returns the first element (in depth-first order, starting with this one) with the given ID, if there is any.
+    /// Tooltips are not searched.
+    pub fn find(&self, id: u32) -> Option<&UiElement<T>> {
+        if self.id == id {
+            return Some(self);
+        }
+        self.content
+            .container()?
+            .get_children()
+            .iter()
+            .find_map(|child| child.find(id))
+    }
+
+    /// Returns the first element (in depth-first order, starting with this one) with the given ID mutably, if there is any.
+    /// Tooltips are not searched.
+    /// Use this to change elements directly from your game state instead of using a message handler and a transition.
+    pub fn find_mut(&mut self, id: u32) -> Option<&mut UiElement<T>> {
+        if self.id == id {
+            return Some(self);
+        }
+        self.content
+            .container_mut()?
+            .get_children_mut()
+            .iter_mut()
+            .find_map(|child| child.find_mut(id))
+    }
+
+    /// Returns all elements among this element and its successors with the given ID, in depth-first order.
+    /// Tooltips are not searched.
+    pub fn find_all(&self, id: u32) -> Vec<&UiElement<T>> {
+        let mut res = Vec::new();
+        self.collect_all(id, &mut res);
+        res
+    }
+
+    /// Adds this element (if it has the given ID) and all successors with the given ID to the passed vector, in depth-first order.
+    fn collect_all<'a>(&'a self, id: u32, res: &mut Vec<&'a UiElement<T>>) {
+        if self.id == id {
+            res.push(self);
+        }
+        if let Some(cont) = self.content.container() {
+            for child in cont.get_children() {
+                child.collect_all(id, res);
+            }
+        }
+    }
+
+    /// Returns the content of this element cast to the type `C`, or None if it is of another type.
+    /// Only works for contents that implement [UiContent::as_any], as all basic contents do. Containers can be accessed via [UiContent::container] instead.
+    pub fn content_as<C: 'static>(&self) -> Option<&C> {
+        self.content.as_any()?.downcast_ref()
+    }
+
+    /// Returns the content of this element mutably cast to the type `C`, or None if it is of another type.
+    /// Only works for contents that implement [UiContent::as_any_mut], as all basic contents do. Containers can be accessed via [UiContent::container_mut] instead.
+    /// As the content may be changed, it is measured again (see [UiContent::measure]) and laid out again before this element is drawn next.
+    /// This overwrites sizes set manually on contents that measure themselves, such as texts and images.
+    pub fn content_as_mut<C: 'static>(&mut self) -> Option<&mut C> {
+        let content = self.content.as_any_mut()?.downcast_mut()?;
+        self.draw_cache = DrawCache::Invalid;
+        self.measure_pending = true;
+        Some(content)
+    }
+
+    /// Applies the styles of the passed theme to this element and all its successors (including tooltips), according to the style classes of each element.
+    /// Classes are applied in the order they were added, so later classes overwrite values set by earlier ones. Classes not contained in the theme are ignored.
+    /// Call this again with another theme to re-skin the UI at runtime. Values set by the previous theme are reset to the values the elements had before any theme was applied.
+    pub fn apply_theme(&mut self, ctx: &Context, theme: &Theme) {
+        self.theme_trigger_sound = None;
+        for style in self.apply_theme_fields(theme) {
+            if let Some(sound) = &style.trigger_sound {
+                match Source::from_data(ctx, sound.clone()) {
+                    Ok(source) => self.theme_trigger_sound = Some(source),
+                    Err(_) => {
+                        if cfg!(debug_assertions) {
+                            println!("[ERROR] Failed to create sound.");
+                        }
+                    }
+                }
+            }
+            self.content.apply_style(ctx, style, &mut self.layout);
+        }
+
+        if let Some(cont) = self.content.container_mut() {
+            for child in cont.get_children_mut() {
+                child.apply_theme(ctx, theme);
+            }
+        }
+        if let Some(tooltip) = &mut self.tooltip {
+            tooltip.apply_theme(ctx, theme);
+        }
+    }
+
+    /// Resets the visuals and padding of this element to the values it had before the first theme was applied, then applies those of the styles of the passed theme matching its classes.
+    /// Returns the matching styles in the order they were applied. Elements without classes are left unchanged.
+    fn apply_theme_fields<'a>(&mut self, theme: &'a Theme) -> Vec<&'a Style> {
+        if self.classes.is_empty() {
+            return Vec::new();
+        }
+
+        let unthemed = *self.unthemed.get_or_insert(theme::Unthemed {
+            visuals: self.visuals,
+            hover_visuals: self.hover_visuals,
+            focus_visuals: self.focus_visuals,
+            padding: self.layout.padding,
+        });
+        self.visuals = unthemed.visuals;
+        self.hover_visuals = unthemed.hover_visuals;
+        self.focus_visuals = unthemed.focus_visuals;
+        self.layout.padding = unthemed.padding;
+        self.draw_cache = DrawCache::Invalid;
+
+        let styles: Vec<&Style> = self
+            .classes
+            .iter()
+            .filter_map(|class| theme.get(class))
+            .collect();
+        for style in &styles {
+            if let Some(visuals) = style.visuals {
+                self.visuals = visuals;
+            }
+            if let Some(hover_visuals) = style.hover_visuals {
+                self.hover_visuals = Some(hover_visuals);
+            }
+            if let Some(focus_visuals) = style.focus_visuals {
+                self.focus_visuals = Some(focus_visuals);
+            }
+            if let Some(padding) = style.padding {
+                self.layout.padding = padding;
+            }
+        }
+        styles
+    }
+
     /// Returns this elements (not neccessarily unique) ID within this UI. This ID is used to indentify the source of intern messages.
     pub fn get_id(&self) -> u32 {
         self.id
@@ -178,28 +516,81 @@ impl<T: Copy + Eq + Hash> UiElement<T> {
     }
 
     /// Receives a data structure containing all messages triggered by your game_state this frame (or None if there were no messages).
+    /// Only the topmost element below the mouse cursor (and its ancestors) react to the mouse.
+    /// Moves the keyboard focus if the user pressed a navigation key (Tab, Shift + Tab, arrow keys) or clicked a focusable element.
     /// It then collects all messages sent by this element and its children and redistributes all of those messages to this element and all children.
-    /// Returns all internal messages to act on them.
+    /// Messages emitted by message handlers (see [HandlerContext::emit]) are distributed as well, in up to 16 further rounds. Messages still emitted after that are distributed during the next update.
+    /// Returns all internal messages (including those emitted by handlers) to act on them.
     /// In addition, if this element has children, all children whose [UiContent::expired] function returns true are removed from the container.
     pub fn update(
         &mut self,
         ctx: &ggez::Context,
         extern_messages: impl Into<Option<HashSet<UiMessage<T>>>>,
+    ) -> HashSet<UiMessage<T>> {
+        self.update_with_input(&InputState::from_context(ctx), extern_messages)
+    }
+
+    /// Same as [UiElement::update], but reads mouse, keyboard and timer from the passed [InputState] instead of a [ggez::Context].
+    /// Use this to feed simulated input to your UI, e.g. in tests (see [UiHarness]).
+    pub fn update_with_input(
+        &mut self,
+        input: &InputState,
+        extern_messages: impl Into<Option<HashSet<UiMessage<T>>>>,
     ) -> HashSet<UiMessage<T>> {
         // Message handling
 
-        let intern_messages = self.collect_messages(ctx);
+        self.update_mouse_hit(input.mouse_position.into());
+        let drag = self.active_drag();
+        let drop_target = self.mark_drop_target(drag.as_ref().map(|(_, payload)| payload));
+        let keyboard_captured = self.captures_keyboard();
+        self.navigate_focus(input, keyboard_captured);
+        let mut intern_messages = self.collect_messages(input, keyboard_captured);
+
+        // a drag ends when the mouse button is released
+        if let (Some((source, payload)), Some(target)) = (drag, drop_target) {
+            if !input.button_pressed(ggez::event::MouseButton::Left) {
+                intern_messages.insert(UiMessage::DragDropped {
+                    source,
+                    target,
+                    payload,
+                });
+            }
+        }
 
-        let all_messages = match extern_messages.into() {
+        let mut all_messages = match extern_messages.into() {
             None => intern_messages.clone(),
-            Some(extern_messages) => intern_messages.union(&extern_messages).copied().collect(),
+            Some(extern_messages) => intern_messages.union(&extern_messages).cloned().collect(),
         };
 
         self.distribute_messages(&all_messages).expect("Something went wrong delivering or executing messages. Probably you wrote a bad handler function.");
 
+        // messages emitted by handlers are distributed in further rounds, each message at most once per update
+        for _ in 0..MAX_MESSAGE_ROUNDS {
+            let mut emitted = HashSet::new();
+            self.collect_emitted_messages(&mut emitted);
+            emitted.retain(|message| !all_messages.contains(message));
+            if emitted.is_empty() {
+                break;
+            }
+
+            self.distribute_messages(&emitted).expect("Something went wrong delivering or executing messages. Probably you wrote a bad handler function.");
+            intern_messages.extend(emitted.iter().cloned());
+            all_messages.extend(emitted);
+        }
+
         intern_messages
     }
 
+    /// Removes all messages emitted by the message handlers of this element and its successors from their outboxes and adds them to the passed set.
+    fn collect_emitted_messages(&mut self, res: &mut HashSet<UiMessage<T>>) {
+        res.extend(self.emitted_messages.drain(..));
+        if let Some(cont) = self.content.container_mut() {
+            for child in cont.get_children_mut() {
+                child.collect_emitted_messages(res);
+            }
+        }
+    }
+
     /// Returns wether this element should be removed by its parents.
     pub(crate) fn expired(&self) -> bool {
         self.content.expired()
@@ -214,45 +605,150 @@ impl<T: Copy + Eq + Hash> UiElement<T> {
         self.update(ctx, extern_messages)
     }
 
-    /// Iterates over this element and all successors and collects all internal messages (clicks) sent during the last frame.
-    fn collect_messages(&self, ctx: &Context) -> HashSet<UiMessage<T>> {
-        let mut res: HashSet<UiMessage<T>> = HashSet::new();
+    /// Passes a mouse wheel event (as received by [ggez::event::EventHandler::mouse_wheel_event]) to this element and its children.
+    /// Children receive the event first, so the innermost content that reacts to the event (e.g. a [containers::ScrollBox]) consumes it.
+    /// Returns true if the event was consumed by any element.
+    /// Call this on your root element from your event handler or scene.
+    pub fn mouse_wheel_event(&mut self, ctx: &Context, x: f32, y: f32) -> bool {
+        self.mouse_wheel_event_with_input(&InputState::from_context(ctx), x, y)
+    }
 
-        if self.id != 0
-            && match self.draw_cache {
-                DrawCache::Invalid => false,
-                DrawCache::Valid {
-                    outer,
-                    inner: _,
-                    target: _,
-                } => outer.contains(ctx.mouse.position()),
+    /// Passes a mouse wheel event to this element and its children just like [UiElement::mouse_wheel_event], but with the passed input state instead of the one of a [Context].
+    pub fn mouse_wheel_event_with_input(&mut self, input: &InputState, x: f32, y: f32) -> bool {
+        self.record_scroll(y);
+        self.propagate_event(&mut |content| content.mouse_wheel_event(input, x, y), true)
+    }
+
+    /// Passes a text input event (as received by [ggez::event::EventHandler::text_input_event]) to this element and its children.
+    /// The event is consumed by the first content that reacts to it (e.g. an active [basic::TextInput]).
+    /// Returns true if the event was consumed by any element.
+    /// Call this on your root element from your event handler or scene.
+    pub fn text_input_event(&mut self, character: char) -> bool {
+        self.propagate_event(&mut |content| content.text_input_event(character), false)
+    }
+
+    /// Passes a key press event (as received by [ggez::event::EventHandler::key_down_event]) to this element and its children.
+    /// The event is consumed by the first content that reacts to it (e.g. an active [basic::TextInput]).
+    /// Returns true if the event was consumed by any element.
+    /// Call this on your root element from your event handler or scene.
+    pub fn key_down_event(&mut self, input: KeyInput) -> bool {
+        self.propagate_event(&mut |content| content.key_down_event(input), false)
+    }
+
+    /// Remembers the passed vertical mouse wheel movement in the element receiving mouse input among this element and its successors, to be sent as [UiMessage::Scrolled].
+    fn record_scroll(&mut self, y: f32) {
+        if self.mouse_target {
+            self.mouse_state.add_scroll(y);
+        }
+        if let Some(cont) = self.content.container_mut() {
+            for child in cont.get_children_mut() {
+                child.record_scroll(y);
             }
-        {
-            if ctx
-                .mouse
-                .button_just_pressed(ggez::event::MouseButton::Left)
-            {
+        }
+    }
+
+    /// Calls the passed event handling function on the contents of all successors of this element and then the content of this element, until one of them returns true.
+    /// If `hovered_only` is set, only elements below the mouse cursor (and not covered by other elements) receive the event.
+    /// Returns true if any content consumed the event.
+    fn propagate_event(
+        &mut self,
+        handler: &mut dyn FnMut(&mut dyn UiContent<T>) -> bool,
+        hovered_only: bool,
+    ) -> bool {
+        if hovered_only && !self.mouse_hit {
+            return false;
+        }
+
+        if let Some(cont) = self.content.container_mut() {
+            for child in cont.get_children_mut() {
+                if child.propagate_event(handler, hovered_only) {
+                    return true;
+                }
+            }
+        }
+
+        handler(self.content.as_mut())
+    }
+
+    /// Returns wether the content of this element or any of its successors currently captures the keyboard.
+    fn captures_keyboard(&self) -> bool {
+        self.content.captures_keyboard()
+            || self.content.container().is_some_and(|cont| {
+                cont.get_children()
+                    .iter()
+                    .any(|child| child.captures_keyboard())
+            })
+    }
+
+    /// Returns the user-editable text of the first element with the given ID in this element or its successors (see [UiContent::text_value]).
+    /// Returns None if there is no such element or it does not contain editable text.
+    pub fn get_text(&self, id: u32) -> Option<&str> {
+        if self.id == id {
+            if let Some(text) = self.content.text_value() {
+                return Some(text);
+            }
+        }
+
+        self.content.container().and_then(|cont| {
+            cont.get_children()
+                .iter()
+                .find_map(|child| child.get_text(id))
+        })
+    }
+
+    /// Returns the current value of the first element with the given ID in this element or its successors that has one (see [UiContent::value]).
+    /// Returns None if there is no such element.
+    pub fn get_value(&self, id: u32) -> Option<UiValue> {
+        if self.id == id {
+            if let Some(value) = self.content.value() {
+                return Some(value);
+            }
+        }
+
+        self.content.container().and_then(|cont| {
+            cont.get_children()
+                .iter()
+                .find_map(|child| child.get_value(id))
+        })
+    }
+
+    /// Iterates over this element and all successors and collects all internal messages (clicks, key presses, messages sent by contents) sent during the last frame.
+    /// If the keyboard is captured by any element, trigger keys are ignored.
+    fn collect_messages(
+        &mut self,
+        input: &InputState,
+        keyboard_captured: bool,
+    ) -> HashSet<UiMessage<T>> {
+        let mut res: HashSet<UiMessage<T>> = HashSet::new();
+        self.mouse_position = input.mouse_position;
+
+        if self.id != 0 {
+            self.mouse_state.collect_messages(
+                input,
+                self.id,
+                self.mouse_hit,
+                self.mouse_target,
+                &mut res,
+            );
+        }
+
+        if self.mouse_target {
+            if input.button_just_pressed(ggez::event::MouseButton::Left) {
                 res.insert(UiMessage::Clicked(self.id));
                 res.insert(UiMessage::Triggered(self.id));
-                if let Some(sound) = &self.trigger_sound {
-                    if sound.play_later().is_err() && cfg!(debug_assertions) {
-                        println!("[ERROR] Failed to play sound.");
-                    }
-                }
+                self.play_trigger_sound();
             }
 
-            if ctx
-                .mouse
-                .button_just_pressed(ggez::event::MouseButton::Right)
-            {
+            if input.button_just_pressed(ggez::event::MouseButton::Right) {
                 res.insert(UiMessage::ClickedRight(self.id));
             }
         }
 
         if self.id != 0
+            && !keyboard_captured
             && self.keys.iter().any(|key_opt| {
                 if let Some(key) = key_opt {
-                    ctx.keyboard.is_key_just_pressed(*key)
+                    input.is_key_just_pressed(*key)
                 } else {
                     false
                 }
@@ -262,18 +758,74 @@ impl<T: Copy + Eq + Hash> UiElement<T> {
             res.insert(UiMessage::Triggered(self.id));
         }
 
-        if let Some(cont) = self.content.container() {
-            for child in cont.get_children() {
-                res.extend(child.collect_messages(ctx));
+        if self.focused
+            && !keyboard_captured
+            && [
+                VirtualKeyCode::Return,
+                VirtualKeyCode::NumpadEnter,
+                VirtualKeyCode::Space,
+            ]
+            .into_iter()
+            .any(|key| input.is_key_just_pressed(key))
+        {
+            res.insert(UiMessage::Triggered(self.id));
+            self.content.focus_triggered();
+            self.play_trigger_sound();
+        }
+
+        if self.gamepad_triggered {
+            self.gamepad_triggered = false;
+            res.insert(UiMessage::PressedGamepadButton(self.id));
+            res.insert(UiMessage::Triggered(self.id));
+            self.play_trigger_sound();
+        }
+
+        if let DrawCache::Valid {
+            inner, transform, ..
+        } = self.draw_cache
+        {
+            self.content
+                .update_input(input, inner, transform, self.mouse_hit);
+        }
+
+        if self.id != 0 {
+            self.content.collect_messages(self.id, &mut res);
+        }
+
+        res.extend(self.transition_messages.drain(..));
+        res.extend(self.emitted_messages.drain(..));
+
+        if let Some(cont) = self.content.container_mut() {
+            for child in cont.get_children_mut() {
+                res.extend(child.collect_messages(input, keyboard_captured));
             }
         }
 
         res
     }
 
+    /// Plays the trigger sound of the current theme or, if it does not set one, this elements trigger sound, if it has one.
+    fn play_trigger_sound(&self) {
+        if let Some(sound) = self
+            .theme_trigger_sound
+            .as_ref()
+            .or(self.trigger_sound.as_ref())
+        {
+            if sound.play_later().is_err() && cfg!(debug_assertions) {
+                println!("[ERROR] Failed to play sound.");
+            }
+        }
+    }
+
     /// Distributes the passed set of [UiMessage]s to this element and all its successors, letting their message handlers react to the messages.
     fn distribute_messages(&mut self, messages: &HashSet<UiMessage<T>>) -> GameResult {
-        (self.message_handler)(messages, self.layout, &mut self.transitions);
+        // the handler is taken out of the element while running, so it can access the element mutably
+        let mut handler = std::mem::replace(&mut self.message_handler, Box::new(|_, _| {}));
+        handler(messages, &mut HandlerContext::new(self));
+        self.message_handler = handler;
+        if let Some(animation) = &mut self.animation {
+            animation.handle_messages(messages);
+        }
 
         if let Some(cont) = self.content.container_mut() {
             // actual distribution
@@ -287,142 +839,241 @@ impl<T: Copy + Eq + Hash> UiElement<T> {
         Ok(())
     }
 
-    /// Adds a transition to the end of the transition queue. It will be executed as soon as all transitions added beforehand have run their course.
+    /// Adds a transition to the end of the transition queue. It will be executed as soon as all transitions added beforehand that share a [TransitionChannel] with it have run their course.
     pub fn add_transition(&mut self, transition: Transition<T>) {
         self.transitions.push_back(transition);
     }
 
-    /// Progresses the currently active transition by the time of the last frame.
-    /// If this ends the current transition, the values of this element are updated to the values given by the transition and it is removed from the queue.
-    fn progress_transitions(&mut self, ctx: &Context) {
-        if !self.transitions.is_empty() && self.transitions[0].progress(ctx.time.delta()) {
-            let trans = self.transitions.pop_front().expect(
-                "Transitions did not contain a first element despite being not empty 2 lines ago.",
-            );
+    /// Removes all transitions from the transition queue. Changes of transitions not yet completed are discarded, so the element returns to the state before they started.
+    pub fn clear_transitions(&mut self) {
+        self.transitions.clear();
+    }
 
-            if let Some(layout) = trans.new_layout {
-                self.layout = layout;
-                self.draw_cache = DrawCache::Invalid;
-            }
-            if let Some(visuals) = trans.new_visuals {
-                self.visuals = visuals;
-            }
-            if let Some(hover_visuals) = trans.new_hover_visuals {
-                self.hover_visuals = hover_visuals;
-            }
-            if let Some(content) = trans.new_content {
-                self.content = content;
-            }
-            if let Some(tooltip) = trans.new_tooltip {
-                self.tooltip = tooltip;
+    /// Immediately completes all transitions in the transition queue in order, jumping to the state after the last of them.
+    pub fn skip_transitions(&mut self) {
+        while let Some(trans) = self.transitions.pop_front() {
+            self.apply_transition(trans);
+        }
+    }
+
+    /// Returns the transition currently progressing on the passed channel, if any.
+    /// This is the first transition in the queue that occupies the channel, unless it is blocked by an earlier transition.
+    fn active_transition(&self, channel: TransitionChannel) -> Option<&Transition<T>> {
+        self.active_transition_index(channel)
+            .map(|index| &self.transitions[index])
+    }
+
+    /// Returns the index of the transition currently progressing on the passed channel, if any. See [UiElement::active_transition].
+    fn active_transition_index(&self, channel: TransitionChannel) -> Option<usize> {
+        self.transitions
+            .iter()
+            .position(|trans| trans.affects(channel))
+            .filter(|index| !self.transition_blocked(*index))
+    }
+
+    /// Returns wether the transition at the passed index in the queue is blocked, i.e. an earlier transition occupies any of the same channels.
+    fn transition_blocked(&self, index: usize) -> bool {
+        let trans = &self.transitions[index];
+        TransitionChannel::ALL
+            .into_iter()
+            .filter(|channel| trans.affects(*channel))
+            .any(|channel| {
+                self.transitions
+                    .iter()
+                    .take(index)
+                    .any(|earlier| earlier.affects(channel))
+            })
+    }
+
+    /// Progresses all currently active transitions by the passed time, usually the duration of the last frame.
+    /// A transition is active if no transition before it in the queue occupies any of the same channels.
+    /// If this ends a transition, the values of this element are updated to the values given by the transition and it is removed from the queue.
+    fn progress_transitions(&mut self, delta: Duration) {
+        let mut occupied = Vec::new();
+        let mut index = 0;
+        while index < self.transitions.len() {
+            let channels: Vec<TransitionChannel> = TransitionChannel::ALL
+                .into_iter()
+                .filter(|channel| self.transitions[index].affects(*channel))
+                .collect();
+            let blocked = channels.iter().any(|channel| occupied.contains(channel));
+            // the channels stay occupied for the rest of this frame even if the transition completes, so the next transition starts next frame
+            occupied.extend(channels);
+
+            if !blocked && self.transitions[index].progress(delta) {
+                if let Some(trans) = self.transitions.remove(index) {
+                    self.apply_transition(trans);
+                }
+            } else {
+                index += 1;
             }
         }
     }
 
+    /// Updates the values of this element to the values given by the passed transition and remembers the messages to send about its completion.
+    fn apply_transition(&mut self, trans: Transition<T>) {
+        if self.id != 0 {
+            self.transition_messages
+                .push(UiMessage::TransitionFinished(self.id));
+        }
+        if let Some(message) = trans.completion_message {
+            self.transition_messages.push(UiMessage::Extern(message));
+        }
+        if let Some(layout) = trans.new_layout {
+            self.layout = layout;
+            self.draw_cache = DrawCache::Invalid;
+        }
+        if let Some(visuals) = trans.new_visuals {
+            self.visuals = visuals;
+        }
+        if let Some(hover_visuals) = trans.new_hover_visuals {
+            self.hover_visuals = hover_visuals;
+        }
+        if let Some(opacity) = trans.new_opacity {
+            self.opacity = opacity;
+        }
+        if let Some(tint) = trans.new_tint {
+            self.tint = tint;
+        }
+        if let Some(transform) = trans.new_transform {
+            self.transform = transform;
+        }
+        if let Some(content) = trans.new_content {
+            self.content = content;
+        }
+        if let Some((x_size, y_size)) = trans.new_content_size {
+            self.layout.x_size = x_size;
+            self.layout.y_size = y_size;
+            self.draw_cache = DrawCache::Invalid;
+        }
+        if let Some(tooltip) = trans.new_tooltip {
+            self.tooltip = tooltip;
+        }
+    }
+
+    /// Progresses the animation of this element (if any) by the passed time, usually the duration of the last frame.
+    fn progress_animation(&mut self, delta: Duration) {
+        if let Some(animation) = &mut self.animation {
+            animation.progress(delta);
+        }
+    }
+
     /// First checks wether the user is currently hovering this element or not and chooses to return visuals or hover visuals accordingly.
     /// Then checks if the transition queue contains a (hover-)visual-changing element and returns an average visuals if needed.
-    fn get_current_visual(&self, ctx: &Context, param: UiDrawParam) -> Visuals {
-        // check if this element is being hovered
+    /// Focus visuals take precedence over all other visuals while this element holds the keyboard focus, drop visuals over all others while an accepted payload is dragged above this element.
+    /// While an animation with visuals is playing, its visuals replace the normal visuals.
+    fn get_current_visual(&self, param: UiDrawParam) -> Visuals {
+        let visuals = self
+            .animation
+            .as_ref()
+            .and_then(|animation| animation.visuals())
+            .unwrap_or(self.visuals);
+
+        if self.drop_hovered {
+            if let Some(drop_visuals) = self.drop_visuals {
+                return drop_visuals;
+            }
+        }
 
-        if param.mouse_listen
-            && match self.draw_cache {
-                DrawCache::Invalid => false,
-                DrawCache::Valid {
-                    outer,
-                    inner: _,
-                    target: _,
-                } => outer.contains(ctx.mouse.position()),
+        if self.focused {
+            if let Some(focus_visuals) = self.focus_visuals {
+                return focus_visuals;
             }
-        {
+        }
+
+        // check if this element is being hovered
+
+        if param.mouse_listen && self.mouse_hit {
             // yes: get what this element, diregarding transitions, would display on hover
             let own_vis = if let Some(hover_visuals) = self.hover_visuals {
                 hover_visuals
             } else {
-                self.visuals
+                visuals
             };
 
-            // check wether there are transitions in the queue
-            if self.transitions.is_empty() {
+            // check wether there are transitions changing the hover visuals in the queue
+            match self.active_transition(TransitionChannel::HoverVisuals) {
                 //no: just return own visuals
-                own_vis
-            } else {
-                // yes: check wether the top transition wants to change hover_visuals
-                let trans = &self.transitions[0];
-                match trans.new_hover_visuals {
+                None => own_vis,
+                // yes: check wether the transition wants to change hover_visuals
+                Some(trans) => match trans.new_hover_visuals {
                     // yes: find out what it wants to display on hover and take the average
                     Some(vis) => {
                         let trans_vis = if let Some(hover_visuals) = vis {
                             hover_visuals
                         } else {
-                            self.visuals
+                            visuals
                         };
                         own_vis.average(trans_vis, trans.get_progress_ratio())
                     }
                     // no: just return own visuals
                     None => own_vis,
-                }
+                },
             }
         } else {
-            // not hovered: check wether there are transitons in the queue
-            if self.transitions.is_empty() {
+            // not hovered: check wether there are transitons changing the visuals in the queue
+            match self.active_transition(TransitionChannel::Visuals) {
                 // no transitions: just return own visuals
-                self.visuals
-            } else {
-                // transitions: check wether the top transition wants to change visuals
-                let trans = &self.transitions[0];
-                match trans.new_visuals {
+                None => visuals,
+                // transitions: check wether the transition wants to change visuals
+                Some(trans) => match trans.new_visuals {
                     // yes: find average between the two visuals
-                    Some(vis) => self.visuals.average(vis, trans.get_progress_ratio()),
+                    Some(vis) => visuals.average(vis, trans.get_progress_ratio()),
                     // no: just return own visuals
-                    None => self.visuals,
-                }
+                    None => visuals,
+                },
             }
         }
     }
 
+    /// Returns the color this element and its children are currently multiplied with, combining tint and opacity and blending towards the values of the active transition.
+    /// The opacity of a playing animation is multiplied in as well.
+    fn get_current_tint(&self) -> Color {
+        let (mut opacity, mut tint) = (self.opacity, self.tint);
+        if let Some(trans) = self.active_transition(TransitionChannel::Visuals) {
+            let ratio = trans.get_progress_ratio();
+            if let Some(new_opacity) = trans.new_opacity {
+                opacity = opacity * (1. - ratio) + new_opacity * ratio;
+            }
+            if let Some(new_tint) = trans.new_tint {
+                tint = transition::average_color(tint, new_tint, ratio);
+            }
+        }
+        if let Some(animation) = &self.animation {
+            opacity *= animation.opacity();
+        }
+        Color {
+            a: tint.a * opacity.clamp(0., 1.),
+            ..tint
+        }
+    }
+
+    /// Returns the transform currently applied to this element and its children, blending towards the transform of the active transition.
+    fn get_current_transform(&self) -> UiTransform {
+        match self.active_transition(TransitionChannel::Layout) {
+            Some(trans) => match trans.new_transform {
+                Some(new_transform) => self
+                    .transform
+                    .average(new_transform, trans.get_progress_ratio()),
+                None => self.transform,
+            },
+            None => self.transform,
+        }
+    }
+
     /// Updates this element's draw cache by checking for validity.
     /// If the draw cache is still valid (see [UiElement::cache_valid]), nothing happens.
     /// Otherwise, the function uses ```content_min```, the ```layout``` and the currently active ```Transition``` to generate a valid draw cache
     /// If no valid draw chache can be generated, the draw_cache wil be reset to default value.
     /// The function will only change ```draw_cache::valid``` to ```true``` if the generated rectangles fit within the target ```rect```.
-    fn update_draw_cache(&mut self, _ctx: &Context, target: Rect) {
+    fn update_draw_cache(&mut self, target: Rect) {
         // check wether draw cache needs to be updated at all (or a transition is going on)
         if !self.cache_valid(target) {
-            // first calculate the target of this element if it were on its own
-            let (own_outer, own_inner) = self
-                .layout
-                .get_outer_inner_bounds_in_target(&target, self.content_min());
-            // check if there is a transition going on
-            let (outer, inner) = if !self.transitions.is_empty() {
-                // the transitions are not empty: check if the top transitions wants to change the layout
-                if let Some(new_layout) = self.transitions[0].new_layout {
-                    let (trans_outer, trans_inner) =
-                        new_layout.get_outer_inner_bounds_in_target(&target, self.content_min());
-                    (
-                        transition::average_rect(
-                            &own_outer,
-                            &trans_outer,
-                            self.transitions[0].get_progress_ratio(),
-                        ),
-                        transition::average_rect(
-                            &own_inner,
-                            &trans_inner,
-                            self.transitions[0].get_progress_ratio(),
-                        ),
-                    )
-                } else {
-                    (own_outer, own_inner)
-                }
-            } else {
-                // draw cache was invalidated by some other means (e.g. by sub element having a transition, the element not being initalized, etc.) -> calculate target
-                (own_outer, own_inner)
-            };
+            let (outer, inner) = self.get_current_bounds(target);
 
             // checking bounds, adding 0.01 to deal with problems stemming from imprecise multiplication
-            if outer.w > target.w + 0.01
-                || outer.h > target.h + 0.01
-                || outer.x < 0.
-                || outer.y < 0.
+            // elements may be (partially) outside of the screen, e.g. within a scroll box, so only the size is checked
+            if outer.w > target.w + 0.01 || outer.h > target.h + 0.01
             //|| outer.x + outer.w > ctx.gfx.window().inner_size().width as f32 + 0.01
             //|| outer.y + outer.h > ctx.gfx.window().inner_size().height as f32 + 0.01
             {
@@ -438,24 +1089,53 @@ impl<T: Copy + Eq + Hash> UiElement<T> {
                     outer,
                     inner,
                     target,
+                    clip: None,
+                    transform: Default::default(),
+                    z: 0,
                 };
             }
         }
     }
 
+    /// Returns the outer and inner bounds of this element when drawn to the passed target, respecting any layout transition currently going on.
+    fn get_current_bounds(&self, target: Rect) -> (Rect, Rect) {
+        // first calculate the target of this element if it were on its own
+        let (own_outer, own_inner) = self
+            .current_layout()
+            .get_outer_inner_bounds_in_target(&target, self.content_min());
+        // check if there is a transition going on
+        if let Some(trans) = self.active_transition(TransitionChannel::Layout) {
+            // there is a layout transition: check if it wants to change the layout
+            if let Some(new_layout) = trans.new_layout {
+                let (trans_outer, trans_inner) =
+                    new_layout.get_outer_inner_bounds_in_target(&target, self.content_min());
+                (
+                    transition::average_rect(&own_outer, &trans_outer, trans.get_progress_ratio()),
+                    transition::average_rect(&own_inner, &trans_inner, trans.get_progress_ratio()),
+                )
+            } else {
+                (own_outer, own_inner)
+            }
+        } else {
+            // draw cache was invalidated by some other means (e.g. by sub element having a transition, the element not being initalized, etc.) -> calculate target
+            (own_outer, own_inner)
+        }
+    }
+
     /// Returns wether this elements cache is still valid. The cache may be invalidated manually or because the target_rect has changed.
-    /// Any chache is considered invalid if there is currently an active transition that is actively changing the layout
+    /// Any chache is considered invalid if there is currently an active transition that is actively changing the layout or cross-fading the content.
     /// In the case of containers, the cache may also be invalidated because the cache of a child element has turned invalid. The default implementation for this case can e.g. be found in the code for [VerticalBox].
     fn cache_valid(&self, target: Rect) -> bool {
         let init = match self.draw_cache {
             DrawCache::Invalid => false,
             DrawCache::Valid {
-                outer: _,
-                inner: _,
                 target: cache_target,
+                ..
             } => cache_target == target,
-        } && (self.transitions.is_empty()
-            || matches!(self.transitions[0].new_layout, None));
+        } && self
+            .active_transition(TransitionChannel::Layout)
+            .is_none_or(|trans| trans.new_layout.is_none())
+            && self.crossfade_transition().is_none();
         match self.content.container() {
             Some(cont) => cont
                 .get_children()
@@ -467,14 +1147,11 @@ impl<T: Copy + Eq + Hash> UiElement<T> {
 
     /// Returns the minimum and maximum width this element this element can have. Calculated from adding left and right padding to the size-data.
     pub fn width_range(&self) -> (f32, f32) {
-        let layout = self.layout;
+        let layout = self.current_layout();
         (
             // get min width by taking minimum of inner min width, clamping it within the bounds given by the layout and adding padding
-            self.content
-                .container()
-                .map(|cont| cont.content_width_range())
-                .unwrap_or((0., f32::INFINITY))
-                .0
+            self.content_min()
+                .x
                 .clamp(layout.x_size.min(), layout.x_size.max())
                 + layout.padding.1
                 + layout.padding.3,
@@ -485,14 +1162,11 @@ impl<T: Copy + Eq + Hash> UiElement<T> {
 
     /// Returns the minimum and maximum height this element this element can have. Calculated from adding top and bottom padding to the size-data.
     pub fn height_range(&self) -> (f32, f32) {
-        let layout = self.layout;
+        let layout = self.current_layout();
         (
             // get min width by taking minimum of inner min width, clamping it within the bounds given by the layout and adding padding
-            self.content
-                .container()
-                .map(|cont| cont.content_height_range())
-                .unwrap_or((0., f32::INFINITY))
-                .0
+            self.content_min()
+                .y
                 .clamp(layout.y_size.min(), layout.y_size.max())
                 + layout.padding.0
                 + layout.padding.2,
@@ -501,22 +1175,72 @@ impl<T: Copy + Eq + Hash> UiElement<T> {
         )
     }
 
+    /// Returns the layout this element is currently sized by.
+    /// While the content is cross-fading, the sizes blend from the layout to the measured sizes of the new content.
+    fn current_layout(&self) -> Layout {
+        match self.crossfade_transition() {
+            Some((index, ratio)) => match self.transitions[index].new_content_size {
+                Some((x_size, y_size)) => Layout {
+                    x_size: self.layout.x_size.average(x_size, ratio),
+                    y_size: self.layout.y_size.average(y_size, ratio),
+                    ..self.layout
+                },
+                None => self.layout,
+            },
+            None => self.layout,
+        }
+    }
+
+    /// Measures the content of this element if it was changed via [UiElement::content_as_mut] since it was last measured,
+    /// and the new contents of all cross-fading transitions in the queue that have not been measured yet.
+    fn measure_changes(&mut self, measure: &dyn Measure) {
+        if std::mem::take(&mut self.measure_pending) {
+            self.content.measure(measure, &mut self.layout);
+        }
+
+        for trans in self.transitions.iter_mut() {
+            if !trans.crossfade || trans.new_content_size.is_some() {
+                continue;
+            }
+            if let Some(new_content) = &mut trans.new_content {
+                let mut layout = self.layout;
+                new_content.measure(measure, &mut layout);
+                trans.new_content_size = Some((layout.x_size, layout.y_size));
+            }
+        }
+    }
+
     /// Returns the minimum size required by the content of this element.
+    /// While the content is cross-fading, this blends between the minimum sizes of the old and new content.
     fn content_min(&self) -> Vec2 {
-        Vec2 {
-            x: self
-                .content
+        let min_size = |content: &dyn UiContent<T>| Vec2 {
+            x: content
                 .container()
                 .map(|cont| cont.content_width_range().0)
                 .unwrap_or_default(),
-            y: self
-                .content
+            y: content
                 .container()
                 .map(|cont| cont.content_height_range().0)
                 .unwrap_or_default(),
+        };
+        let own = min_size(self.content.as_ref());
+        match self.crossfade_transition() {
+            Some((index, ratio)) => match &self.transitions[index].new_content {
+                Some(new_content) => own.lerp(min_size(new_content.as_ref()), ratio),
+                None => own,
+            },
+            None => own,
         }
     }
 
+    /// Returns the index and progress ratio of the transition currently cross-fading the content of this element, if any.
+    fn crossfade_transition(&self) -> Option<(usize, f32)> {
+        let index = self.active_transition_index(TransitionChannel::Content)?;
+        let trans = &self.transitions[index];
+        (trans.crossfade && trans.new_content.is_some())
+            .then(|| (index, trans.get_progress_ratio()))
+    }
+
     /// Takes in a rectangle target, a canvas, a context and draws the UiElement to that rectangle within that canvas using that context.
     /// The element will either completely fit within the rectangle (including its padding) or not be drawn at all.
     /// The element will align and offset itself within the rectangle.
@@ -526,35 +1250,127 @@ impl<T: Copy + Eq + Hash> UiElement<T> {
         canvas: &mut Canvas,
         param: UiDrawParam,
     ) {
-        self.progress_transitions(ctx);
+        self.measure_changes(&ctx.gfx);
+        self.progress_transitions(ctx.time.delta());
+        self.progress_animation(ctx.time.delta());
 
         // update draw_cache
-        self.update_draw_cache(ctx, param.target);
+        self.update_draw_cache(param.target);
 
         // if draw chache is still invalid, early return and try again next frame
 
-        let (outer, inner) = match self.draw_cache {
+        let own_transform = self.get_current_transform();
+        // animations move the element without changing its layout
+        let animation_offset = self
+            .animation
+            .as_ref()
+            .map(|animation| animation.offset())
+            .unwrap_or_default();
+
+        let (outer, inner) = match &mut self.draw_cache {
             DrawCache::Invalid => return,
             DrawCache::Valid {
                 outer,
                 inner,
-                target: _,
-            } => (outer, inner),
+                clip,
+                transform,
+                z,
+                ..
+            } => {
+                // the clip rect, transformation and z-level may change without the target changing, so they are always updated
+                *clip = param.clip;
+                let (mut outer, mut inner) = (*outer, *inner);
+                outer.translate(animation_offset);
+                inner.translate(animation_offset);
+                *transform = param.transform
+                    * own_transform.to_affine(outer)
+                    * Affine2::from_translation(animation_offset);
+                *z = param.param.z;
+                (outer, inner)
+            }
         };
 
+        // while being dragged, the element follows the mouse cursor and is drawn unclipped above its surroundings
+        let (outer, inner, param) = match self.drag_offset() {
+            None => (outer, inner, param),
+            Some(offset) => {
+                let offset = param.transform.inverse().transform_vector2(offset);
+                let (mut outer, mut inner) = (outer, inner);
+                outer.translate(offset);
+                inner.translate(offset);
+                (outer, inner, param.z_level(param.param.z + 1).no_clip())
+            }
+        };
+
+        // opacity and tint are passed on to the content and thus to all children
+        let param = param.tint(self.get_current_tint());
+
+        // the transform is applied to visuals and content (and thus all children) by changing the projection of the canvas
+        let affine = own_transform.to_affine(outer);
+        let screen_param = param;
+        let param = param.transformed(affine);
+        let previous_projection = (!own_transform.is_identity()).then(|| {
+            let previous = canvas.projection();
+            canvas.set_projection(Mat4::from(previous) * ui_transform::affine_to_mat4(affine));
+            previous
+        });
+
         // draw visuals
-        self.get_current_visual(ctx, param)
+        self.get_current_visual(param)
             .draw(ctx, canvas, param.target(outer));
 
         // draw content
 
-        self.content.draw_content(ctx, canvas, param.target(inner));
+        let content_param = if self.clip_children {
+            param.target(inner).clip(outer)
+        } else {
+            param.target(inner)
+        }
+        .mouse_listen(param.mouse_listen && self.mouse_hit);
+
+        match self.crossfade_transition() {
+            None => self.content.draw_content(ctx, canvas, content_param),
+            // while cross-fading, the old content fades out as the new one fades in
+            Some((index, ratio)) => {
+                let ratio = ratio.clamp(0., 1.);
+                self.content.draw_content(
+                    ctx,
+                    canvas,
+                    content_param.tint(Color::new(1., 1., 1., 1. - ratio)),
+                );
+                if let Some(new_content) = &mut self.transitions[index].new_content {
+                    new_content.draw_content(
+                        ctx,
+                        canvas,
+                        content_param
+                            .tint(Color::new(1., 1., 1., ratio))
+                            .mouse_listen(false),
+                    );
+                }
+            }
+        }
+
+        if let Some(previous) = previous_projection {
+            canvas.set_projection(previous);
+        }
 
         // draw tooltip
-        if param.mouse_listen && outer.contains(ctx.mouse.position()) {
+        if param.mouse_listen && self.mouse_hit {
             if let Some(tt) = &mut self.tooltip {
+                // tooltips are positioned and drawn in screen coordinates, regardless of the transforms of their element and its ancestors
+                let inner = ui_transform::transform_rect(param.transform, inner);
+                let previous_projection =
+                    (screen_param.transform != Default::default()).then(|| {
+                        let previous = canvas.projection();
+                        canvas.set_projection(
+                            Mat4::from(previous)
+                                * ui_transform::affine_to_mat4(screen_param.transform.inverse()),
+                        );
+                        previous
+                    });
+
                 // get relevant positions
-                let mouse_pos = ctx.mouse.position();
+                let mouse_pos = self.mouse_position;
                 let screen_size = ctx.gfx.window().inner_size();
                 let tt_size = (tt.width_range().0, tt.height_range().0);
 
@@ -580,13 +1396,20 @@ impl<T: Copy + Eq + Hash> UiElement<T> {
                     canvas,
                     param
                         .target(Rect::new(x, y, tt_size.0, tt_size.1))
-                        .z_level(param.param.z + 1),
+                        .z_level(param.param.z + 1)
+                        .no_clip()
+                        .no_transform(),
                 );
+
+                if let Some(previous) = previous_projection {
+                    canvas.set_projection(previous);
+                }
             }
         }
     }
 
     /// Draws this UiElement to the current screen. Call this on your root element every frame.
+    /// Hover visuals and tooltips are only displayed if [UiElement::update] is also called every frame, as it determines which element is below the mouse cursor.
     pub fn draw_to_screen(&mut self, ctx: &mut Context, canvas: &mut Canvas, mouse_listen: bool) {
         self.draw_to_rectangle(
             ctx,
@@ -0,0 +1,317 @@
+use std::{collections::HashSet, hash::Hash, time::Duration};
+
+use ggez::glam::Vec2;
+
+use super::{Easing, UiMessage, Visuals};
+
+/// A single keyframe of an [Animation], describing the values the animated element has at a certain point in time.
+/// Values not set in a keyframe are interpolated between the surrounding keyframes that do set them.
+#[derive(Clone, Copy, Debug)]
+pub struct Keyframe {
+    /// The time since the start of the animation cycle this keyframe is reached at.
+    time: Duration,
+    /// The visuals of the element at this keyframe.
+    visuals: Option<Visuals>,
+    /// The offset (in pixels) the element is drawn at relative to its layout position at this keyframe.
+    offset: Option<Vec2>,
+    /// The opacity of the element at this keyframe, multiplied with its own opacity.
+    opacity: Option<f32>,
+    /// The easing curve used when interpolating from the previous keyframe to this one.
+    easing: Easing,
+}
+
+impl Keyframe {
+    /// Creates a new keyframe reached at the specified time since the start of the animation cycle, not changing any values.
+    pub fn new(time: Duration) -> Self {
+        Self {
+            time,
+            visuals: None,
+            offset: None,
+            opacity: None,
+            easing: Easing::Linear,
+        }
+    }
+
+    /// Sets the visuals the element displays at this keyframe. They replace the elements normal visuals while the animation is running.
+    pub fn with_visuals(mut self, visuals: Visuals) -> Self {
+        self.visuals = Some(visuals);
+        self
+    }
+
+    /// Sets the offset (in pixels) the element (and its children) is drawn at relative to its layout position at this keyframe. Other elements are not moved.
+    pub fn with_offset(mut self, x: f32, y: f32) -> Self {
+        self.offset = Some(Vec2::new(x, y));
+        self
+    }
+
+    /// Sets the opacity of the element (and its children) at this keyframe. This is multiplied with the opacity of the element itself.
+    pub fn with_opacity(mut self, opacity: f32) -> Self {
+        self.opacity = Some(opacity);
+        self
+    }
+
+    /// Sets the easing curve used when interpolating from the previous keyframe to this one. Default is [Easing::Linear].
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+}
+
+/// Describes how an [Animation] continues after reaching its last keyframe.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AnimationMode {
+    /// The animation jumps back to its first keyframe and starts over.
+    #[default]
+    Loop,
+    /// The animation plays backwards to its first keyframe, then forwards again, and so on.
+    PingPong,
+}
+
+/// An animation that can be attached to a UiElement to continuously change its visuals, offset and opacity along a list of keyframes.
+/// Unlike a [super::Transition], an animation is not consumed once it completes, but repeats according to its mode and repeat count.
+/// It plays independently of the transition queue of its element and can be paused, resumed and stopped by messages.
+pub struct Animation<T: Copy + Eq + Hash> {
+    /// The keyframes of this animation, sorted by time.
+    keyframes: Vec<Keyframe>,
+    /// How this animation continues after reaching its last keyframe.
+    mode: AnimationMode,
+    /// The number of cycles (one pass through all keyframes, in either direction) this animation plays before finishing. None repeats forever.
+    repeat_count: Option<u32>,
+
+    /// The time elapsed in the current cycle.
+    elapsed: Duration,
+    /// The number of cycles completed so far.
+    cycles: u32,
+    /// Wether this animation is currently progressing.
+    playing: bool,
+    /// Wether this animation has been stopped and currently does not change its element at all.
+    stopped: bool,
+
+    /// Messages that pause this animation when received by its element.
+    pause_messages: HashSet<UiMessage<T>>,
+    /// Messages that resume (or restart, if stopped or finished) this animation when received by its element.
+    play_messages: HashSet<UiMessage<T>>,
+    /// Messages that stop this animation and reset it to its start when received by its element.
+    stop_messages: HashSet<UiMessage<T>>,
+}
+
+impl<T: Copy + Eq + Hash> std::fmt::Debug for Animation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Animation")
+            .field("keyframes", &self.keyframes)
+            .field("mode", &self.mode)
+            .field("repeat_count", &self.repeat_count)
+            .field("elapsed", &self.elapsed)
+            .field("cycles", &self.cycles)
+            .field("playing", &self.playing)
+            .field("stopped", &self.stopped)
+            .finish()
+    }
+}
+
+impl<T: Copy + Eq + Hash> Default for Animation<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Eq + Hash> Animation<T> {
+    /// Creates a new, empty animation that loops forever and starts playing immediately.
+    pub fn new() -> Self {
+        Self {
+            keyframes: Vec::new(),
+            mode: AnimationMode::Loop,
+            repeat_count: None,
+            elapsed: Duration::ZERO,
+            cycles: 0,
+            playing: true,
+            stopped: false,
+            pause_messages: HashSet::new(),
+            play_messages: HashSet::new(),
+            stop_messages: HashSet::new(),
+        }
+    }
+
+    /// Adds a keyframe to this animation. Keyframes may be added in any order. The time of the last keyframe determines the length of a cycle.
+    pub fn with_keyframe(mut self, keyframe: Keyframe) -> Self {
+        let index = self.keyframes.partition_point(|k| k.time <= keyframe.time);
+        self.keyframes.insert(index, keyframe);
+        self
+    }
+
+    /// Sets how this animation continues after reaching its last keyframe. Default is [AnimationMode::Loop].
+    pub fn with_mode(mut self, mode: AnimationMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the number of cycles this animation plays before finishing and holding the values of its final position.
+    /// In [AnimationMode::PingPong], the way back counts as a cycle of its own. By default, animations repeat forever.
+    pub fn with_repeat_count(mut self, repeat_count: u32) -> Self {
+        self.repeat_count = Some(repeat_count);
+        self
+    }
+
+    /// Sets wether this animation starts playing immediately (the default) or waits for a play message.
+    pub fn with_autoplay(mut self, autoplay: bool) -> Self {
+        self.playing = autoplay;
+        self
+    }
+
+    /// Adds a message that pauses this animation when received by its element. The element keeps the values of the current position.
+    pub fn with_pause_message(mut self, message: UiMessage<T>) -> Self {
+        self.pause_messages.insert(message);
+        self
+    }
+
+    /// Adds a message that resumes this animation when received by its element. A stopped or finished animation starts over.
+    pub fn with_play_message(mut self, message: UiMessage<T>) -> Self {
+        self.play_messages.insert(message);
+        self
+    }
+
+    /// Adds a message that stops this animation when received by its element. The element returns to its own values until the animation is played again.
+    pub fn with_stop_message(mut self, message: UiMessage<T>) -> Self {
+        self.stop_messages.insert(message);
+        self
+    }
+
+    /// Resumes this animation. A stopped or finished animation starts over.
+    pub fn play(&mut self) {
+        if self.stopped || self.finished() {
+            self.elapsed = Duration::ZERO;
+            self.cycles = 0;
+        }
+        self.stopped = false;
+        self.playing = true;
+    }
+
+    /// Pauses this animation at its current position.
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    /// Stops this animation and resets it to its start. Until it is played again, it does not change its element.
+    pub fn stop(&mut self) {
+        self.elapsed = Duration::ZERO;
+        self.cycles = 0;
+        self.playing = false;
+        self.stopped = true;
+    }
+
+    /// Returns wether this animation is currently progressing.
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Returns wether this animation has played all of its cycles.
+    pub fn finished(&self) -> bool {
+        self.repeat_count
+            .is_some_and(|count| self.cycles >= count.max(1))
+    }
+
+    /// Pauses, resumes or stops this animation according to the passed messages. Stopping takes precedence over pausing, pausing over playing.
+    pub(crate) fn handle_messages(&mut self, messages: &HashSet<UiMessage<T>>) {
+        let received = |set: &HashSet<UiMessage<T>>| set.iter().any(|m| messages.contains(m));
+        if received(&self.stop_messages) {
+            self.stop();
+        } else if received(&self.pause_messages) {
+            self.pause();
+        } else if received(&self.play_messages) {
+            self.play();
+        }
+    }
+
+    /// Progresses this animation by the passed time, if it is playing.
+    pub(crate) fn progress(&mut self, delta: Duration) {
+        let cycle = self.cycle_duration();
+        if !self.playing || cycle.is_zero() {
+            return;
+        }
+
+        self.elapsed += delta;
+        while self.elapsed >= cycle {
+            self.elapsed -= cycle;
+            self.cycles += 1;
+            if self.finished() {
+                self.playing = false;
+                return;
+            }
+        }
+    }
+
+    /// Returns the length of a single cycle of this animation, i.e. the time of its last keyframe.
+    fn cycle_duration(&self) -> Duration {
+        self.keyframes.last().map(|k| k.time).unwrap_or_default()
+    }
+
+    /// Returns the time within a cycle that currently determines the values of this animation, respecting the direction of play.
+    fn current_time(&self) -> Duration {
+        let cycle = self.cycle_duration();
+        // a finished animation holds the values of the end of its last cycle
+        let (elapsed, cycles) = if self.finished() {
+            (cycle, self.cycles.saturating_sub(1))
+        } else {
+            (self.elapsed, self.cycles)
+        };
+        if self.mode == AnimationMode::PingPong && cycles % 2 == 1 {
+            cycle.saturating_sub(elapsed)
+        } else {
+            elapsed
+        }
+    }
+
+    /// Samples a single property of this animation at its current position, interpolating between the surrounding keyframes that set it.
+    /// Returns None if the animation is stopped or no keyframe sets the property.
+    fn sample<V: Copy>(
+        &self,
+        property: impl Fn(&Keyframe) -> Option<V>,
+        interpolate: impl Fn(V, V, f32) -> V,
+    ) -> Option<V> {
+        if self.stopped {
+            return None;
+        }
+        let time = self.current_time();
+        let previous = self
+            .keyframes
+            .iter()
+            .rev()
+            .filter(|k| k.time <= time)
+            .find_map(|k| property(k).map(|value| (k.time, value)));
+        let next = self
+            .keyframes
+            .iter()
+            .filter(|k| k.time > time)
+            .find_map(|k| property(k).map(|value| (k, value)));
+
+        match (previous, next) {
+            (Some((from_time, from)), Some((keyframe, to))) => {
+                let ratio =
+                    (time - from_time).as_secs_f32() / (keyframe.time - from_time).as_secs_f32();
+                Some(interpolate(from, to, keyframe.easing.apply(ratio)))
+            }
+            (Some((_, value)), None) | (None, Some((_, value))) => Some(value),
+            (None, None) => None,
+        }
+    }
+
+    /// Returns the visuals this animation currently assigns to its element, if any.
+    pub(crate) fn visuals(&self) -> Option<Visuals> {
+        self.sample(|k| k.visuals, |from, to, ratio| from.average(to, ratio))
+    }
+
+    /// Returns the offset this animation currently moves its element by.
+    pub(crate) fn offset(&self) -> Vec2 {
+        self.sample(|k| k.offset, |from, to, ratio| from.lerp(to, ratio))
+            .unwrap_or_default()
+    }
+
+    /// Returns the factor this animation currently multiplies the opacity of its element with.
+    pub(crate) fn opacity(&self) -> f32 {
+        self.sample(
+            |k| k.opacity,
+            |from, to, ratio| from * (1. - ratio) + to * ratio,
+        )
+        .unwrap_or(1.)
+    }
+}
@@ -18,4 +18,12 @@ impl<T: Copy + Eq + Hash> ui::UiContent<T> for () {
         _param: ui::UiDrawParam,
     ) {
     }
+
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
+
+    fn as_any_mut(&mut self) -> Option<&mut dyn std::any::Any> {
+        Some(self)
+    }
 }
@@ -26,6 +26,13 @@ impl<T: Copy + Eq + Hash> ui::UiContent<T> for ggez::graphics::Image {
             .with_preserve_ratio(true)
     }
 
+    fn measure(&mut self, measure: &dyn ui::Measure, layout: &mut ui::Layout) {
+        let size = measure.image_size(self);
+        layout.x_size = ui::Size::Fill(size.x, f32::INFINITY);
+        layout.y_size = ui::Size::Fill(size.y, f32::INFINITY);
+        layout.preserve_ratio = true;
+    }
+
     fn draw_content(
         &mut self,
         ctx: &mut Context,
@@ -33,15 +40,25 @@ impl<T: Copy + Eq + Hash> ui::UiContent<T> for ggez::graphics::Image {
         param: ui::UiDrawParam,
     ) {
         if let Some(dim) = self.dimensions(ctx) {
-            canvas.draw(
-                self,
-                param.param.dest_rect(Rect::new(
-                    param.target.x,
-                    param.target.y,
-                    param.target.w / dim.w,
-                    param.target.h / dim.h,
-                )),
-            );
+            param.draw_clipped(canvas, |canvas| {
+                canvas.draw(
+                    self,
+                    param.param.dest_rect(Rect::new(
+                        param.target.x,
+                        param.target.y,
+                        param.target.w / dim.w,
+                        param.target.h / dim.h,
+                    )),
+                );
+            });
         }
     }
+
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
+
+    fn as_any_mut(&mut self) -> Option<&mut dyn std::any::Any> {
+        Some(self)
+    }
 }
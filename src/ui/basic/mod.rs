@@ -4,3 +4,8 @@ mod empty_element;
 mod image_element;
 /// Contains the implementation of the [crate::UiElement] trait for [ggez::graphics::Text].
 mod text_element;
+/// Contains the [TextInput] struct.
+mod text_input;
+
+/// Basic editable text element.
+pub use text_input::TextInput;
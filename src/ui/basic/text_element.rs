@@ -1,5 +1,5 @@
 use ggez::{
-    graphics::{Canvas, Drawable, Rect},
+    graphics::{Canvas, Color, Drawable, Rect},
     Context,
 };
 use std::hash::Hash;
@@ -26,17 +26,66 @@ impl<T: Copy + Eq + Hash> ui::UiContent<T> for ggez::graphics::Text {
             .with_preserve_ratio(true)
     }
 
+    fn apply_style(&mut self, ctx: &Context, style: &ui::Style, layout: &mut ui::Layout) {
+        if style.font.is_none() && style.text_scale.is_none() {
+            return;
+        }
+        if let Some(font) = &style.font {
+            self.set_font(font);
+        }
+        if let Some(scale) = style.text_scale {
+            self.set_scale(scale);
+        }
+        // the element needs to be resized to fit the new text, just as in to_element_builder
+        if let Some(size) = self.dimensions(&ctx.gfx) {
+            layout.x_size = ui::Size::Fill(size.w, f32::INFINITY);
+            layout.y_size = ui::Size::Fixed(size.h);
+        }
+    }
+
+    fn measure(&mut self, measure: &dyn ui::Measure, layout: &mut ui::Layout) {
+        let size = measure.text_size(self);
+        layout.x_size = ui::Size::Fill(size.x, f32::INFINITY);
+        layout.y_size = ui::Size::Fixed(size.y);
+        layout.preserve_ratio = true;
+    }
+
     fn draw_content(&mut self, ctx: &mut Context, canvas: &mut Canvas, param: ui::UiDrawParam) {
         if let Some(dim) = self.dimensions(ctx) {
-            canvas.draw(
-                self,
-                param.param.dest_rect(Rect::new(
-                    param.target.x,
-                    param.target.y,
-                    param.target.w / dim.w,
-                    param.target.h / dim.h,
-                )),
-            );
+            // fragments with their own color ignore the color of the param, so they are tinted manually
+            let tinted = if param.param.color != Color::WHITE
+                && self
+                    .fragments()
+                    .iter()
+                    .any(|fragment| fragment.color.is_some())
+            {
+                let mut tinted = self.clone();
+                for fragment in tinted.fragments_mut() {
+                    fragment.color = fragment.color.map(|color| param.tinted(color));
+                }
+                Some(tinted)
+            } else {
+                None
+            };
+            param.draw_clipped(canvas, |canvas| {
+                canvas.draw(
+                    tinted.as_ref().unwrap_or(self),
+                    param.param.dest_rect(Rect::new(
+                        param.target.x,
+                        param.target.y,
+                        param.target.w / dim.w,
+                        param.target.h / dim.h,
+                    )),
+                );
+            });
         }
     }
+
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
+
+    fn as_any_mut(&mut self) -> Option<&mut dyn std::any::Any> {
+        Some(self)
+    }
 }
@@ -0,0 +1,675 @@
+use std::{collections::HashSet, hash::Hash, time::Duration};
+
+use ggez::{
+    glam::{Affine2, Vec2},
+    graphics::{self, Color, DrawParam, Quad, Rect, Text, TextFragment},
+    input::keyboard::{KeyCode, KeyInput, KeyMods},
+    Context,
+};
+
+use crate::ui;
+
+/// The time the caret of an active [TextInput] stays visible or invisible while blinking.
+const CARET_BLINK: Duration = Duration::from_millis(500);
+
+/// An editable text field. Can be single-line or multi-line.
+/// The field becomes active when clicked (or triggered while focused) and inactive when the user clicks somewhere else, presses escape or moves the focus on with tab.
+/// Key presses the field does not handle are not consumed, so they still reach the rest of the UI and the game.
+/// While active, it displays a caret and receives text input, allowing cursor movement (arrow keys, home, end, hold ctrl to jump words), selection (hold shift) and deletion (backspace, delete).
+/// When its text is changed by the user, the element sends a [ui::UiMessage::TextChanged] and a [ui::UiMessage::ValueChanged] message.
+/// Pressing enter (ctrl + enter for multi-line fields) sends a [ui::UiMessage::TextSubmitted] message.
+/// The current text can be read via [ui::UiElement::get_text] or [ui::UiElement::get_value].
+///
+/// Text input and key presses are not part of the ggez [Context], so remember to forward them to your UI via [ui::UiElement::text_input_event] and [ui::UiElement::key_down_event] (the [crate::scene_manager::SceneManager] forwards them to your [crate::scene_manager::Scene]).
+#[derive(Debug, Clone)]
+pub struct TextInput {
+    /// The current text.
+    value: String,
+    /// The position of the caret, in characters.
+    cursor: usize,
+    /// The other end of the current selection (the first end being the cursor), in characters. None if nothing is selected.
+    anchor: Option<usize>,
+    /// The text displayed while the field is empty and inactive.
+    placeholder: String,
+    /// The maximum number of characters this field accepts.
+    max_length: Option<usize>,
+    /// Wether this field accepts line breaks.
+    multiline: bool,
+    /// The number of lines a multi-line field displays.
+    rows: usize,
+    /// The font used to display the text. None uses the ggez default font.
+    font: Option<String>,
+    /// The scale (in pixels) of the text.
+    scale: f32,
+    /// The color of the text.
+    color: Color,
+    /// The color of the placeholder.
+    placeholder_color: Color,
+    /// The color of the background of selected text.
+    selection_color: Color,
+    /// Wether this field is currently receiving input.
+    active: bool,
+    /// Wether the text has been changed by the user since the last message collection.
+    changed: bool,
+    /// Wether the text has been submitted by the user since the last message collection.
+    submitted: bool,
+    /// The time since the caret last switched between visible and invisible.
+    blink_time: Duration,
+    /// The amount of pixels the text is currently moved to keep the caret visible.
+    scroll: Vec2,
+    /// The text passed to [TextInput::with_text], before the maximum length and line breaks were applied.
+    initial_text: Option<String>,
+    /// The text the caret offsets were last measured for.
+    measured: String,
+    /// The horizontal caret offset of every column of every line of the measured text, used to place the caret when clicked.
+    offsets: Vec<Vec<f32>>,
+}
+
+impl TextInput {
+    /// Creates a new, empty, single-line text field without a placeholder.
+    pub fn new() -> Self {
+        Self {
+            value: String::new(),
+            cursor: 0,
+            anchor: None,
+            placeholder: String::new(),
+            max_length: None,
+            multiline: false,
+            rows: 1,
+            font: None,
+            scale: 24.,
+            color: Color::WHITE,
+            placeholder_color: Color::from_rgba(255, 255, 255, 120),
+            selection_color: Color::from_rgba(80, 120, 200, 160),
+            active: false,
+            changed: false,
+            submitted: false,
+            blink_time: Duration::ZERO,
+            scroll: Vec2::ZERO,
+            initial_text: None,
+            measured: String::new(),
+            offsets: Vec::new(),
+        }
+    }
+
+    /// Sets the initial text of this field.
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        let text: String = text.into();
+        self.set_text(text.clone());
+        self.initial_text = Some(text);
+        self
+    }
+
+    /// Sets the text displayed while this field is empty and not active.
+    pub fn with_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the maximum number of characters this field accepts. Pass None to allow any length.
+    pub fn with_max_length(mut self, max_length: impl Into<Option<usize>>) -> Self {
+        self.max_length = max_length.into();
+        self.reapply_initial_text();
+        self
+    }
+
+    /// Makes this field accept line breaks and display the specified number of lines.
+    pub fn with_multiline(mut self, rows: usize) -> Self {
+        self.multiline = true;
+        self.rows = rows.max(1);
+        self.reapply_initial_text();
+        self
+    }
+
+    /// Sets the text passed to [TextInput::with_text] again, so it is cut and broken according to the limits set after it.
+    fn reapply_initial_text(&mut self) {
+        if let Some(text) = self.initial_text.clone() {
+            self.set_text(text);
+        }
+    }
+
+    /// Sets the font of this field. The font has to be registered with ggez via [ggez::graphics::GraphicsContext::add_font].
+    pub fn with_font(mut self, font: impl Into<String>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the scale (text height in pixels) of this field.
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets the color of the text and the caret as well as the color of the placeholder.
+    pub fn with_colors(mut self, color: Color, placeholder_color: Color) -> Self {
+        self.color = color;
+        self.placeholder_color = placeholder_color;
+        self
+    }
+
+    /// Returns the current text of this field.
+    pub fn get_text(&self) -> &str {
+        &self.value
+    }
+
+    /// Replaces the text of this field (cut to the maximum length) and moves the caret to its end.
+    /// Does not send a [ui::UiMessage::TextChanged] or [ui::UiMessage::ValueChanged] message.
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        let mut text: String = text.into();
+        if !self.multiline {
+            text = text.replace('\n', " ");
+        }
+        self.value = match self.max_length {
+            Some(max) => text.chars().take(max).collect(),
+            None => text,
+        };
+        self.cursor = self.value.chars().count();
+        self.anchor = None;
+        self.initial_text = None;
+    }
+
+    /// Returns wether this field is currently receiving input.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Activates or deactivates this field.
+    pub fn set_active(&mut self, active: bool) {
+        self.active = active;
+        self.anchor = None;
+        self.blink_time = Duration::ZERO;
+    }
+
+    /// Returns the byte index of the character with the given index.
+    fn byte_index(&self, char_index: usize) -> usize {
+        self.value
+            .char_indices()
+            .nth(char_index)
+            .map(|(i, _)| i)
+            .unwrap_or(self.value.len())
+    }
+
+    /// Returns the currently selected character range, if the selection is not empty.
+    fn selection(&self) -> Option<(usize, usize)> {
+        self.anchor
+            .filter(|anchor| *anchor != self.cursor)
+            .map(|anchor| (anchor.min(self.cursor), anchor.max(self.cursor)))
+    }
+
+    /// Removes the currently selected text. Returns true if there was anything to remove.
+    fn delete_selection(&mut self) -> bool {
+        match self.selection() {
+            Some((start, end)) => {
+                let range = self.byte_index(start)..self.byte_index(end);
+                self.value.replace_range(range, "");
+                self.cursor = start;
+                self.anchor = None;
+                self.changed = true;
+                true
+            }
+            None => {
+                self.anchor = None;
+                false
+            }
+        }
+    }
+
+    /// Replaces the selection with the passed string, inserting as many characters as the maximum length permits.
+    fn insert(&mut self, text: &str) {
+        self.delete_selection();
+        let free = self
+            .max_length
+            .map(|max| max.saturating_sub(self.value.chars().count()))
+            .unwrap_or(usize::MAX);
+        let text: String = text.chars().take(free).collect();
+        if !text.is_empty() {
+            let index = self.byte_index(self.cursor);
+            self.value.insert_str(index, &text);
+            self.cursor += text.chars().count();
+            self.changed = true;
+        }
+    }
+
+    /// Deletes the current selection or, if there is none, the character (or word, if `word` is set) before or after the cursor.
+    fn delete(&mut self, forward: bool, word: bool) {
+        if self.delete_selection() {
+            return;
+        }
+        let (start, end) = match (forward, word) {
+            (false, _) if self.cursor == 0 => return,
+            (true, _) if self.cursor >= self.value.chars().count() => return,
+            (false, true) => (self.word_boundary(false), self.cursor),
+            (false, false) => (self.cursor - 1, self.cursor),
+            (true, true) => (self.cursor, self.word_boundary(true)),
+            (true, false) => (self.cursor, self.cursor + 1),
+        };
+        let range = self.byte_index(start)..self.byte_index(end);
+        self.value.replace_range(range, "");
+        self.cursor = start;
+        self.changed = true;
+    }
+
+    /// Moves the cursor to the passed position, extending the selection if requested or removing it otherwise.
+    fn move_cursor(&mut self, position: usize, select: bool) {
+        if select {
+            self.anchor.get_or_insert(self.cursor);
+        } else {
+            self.anchor = None;
+        }
+        self.cursor = position.min(self.value.chars().count());
+    }
+
+    /// Returns the (line, column) of the passed character index.
+    fn line_column(&self, char_index: usize) -> (usize, usize) {
+        let before: Vec<char> = self.value.chars().take(char_index).collect();
+        let line = before.iter().filter(|c| **c == '\n').count();
+        let column = before.iter().rev().take_while(|c| **c != '\n').count();
+        (line, column)
+    }
+
+    /// Returns the character index of the passed (line, column), clamping the column to the length of the line.
+    fn char_index(&self, line: usize, column: usize) -> usize {
+        let mut index = 0;
+        for (i, content) in self.value.split('\n').enumerate() {
+            let len = content.chars().count();
+            if i == line {
+                return index + column.min(len);
+            }
+            index += len + 1;
+        }
+        self.value.chars().count()
+    }
+
+    /// Returns the index of the start of the word left of the cursor (or the end of the word right of the cursor).
+    fn word_boundary(&self, forward: bool) -> usize {
+        let chars: Vec<char> = self.value.chars().collect();
+        let mut i = self.cursor;
+        if forward {
+            while i < chars.len() && !chars[i].is_alphanumeric() {
+                i += 1;
+            }
+            while i < chars.len() && chars[i].is_alphanumeric() {
+                i += 1;
+            }
+        } else {
+            while i > 0 && !chars[i - 1].is_alphanumeric() {
+                i -= 1;
+            }
+            while i > 0 && chars[i - 1].is_alphanumeric() {
+                i -= 1;
+            }
+        }
+        i
+    }
+
+    /// Creates a ggez text displaying the passed string in the style of this field.
+    fn styled_text(&self, text: &str, color: Color) -> Text {
+        let mut fragment = TextFragment::new(text).scale(self.scale).color(color);
+        if let Some(font) = &self.font {
+            fragment = fragment.font(font.clone());
+        }
+        Text::new(fragment)
+    }
+
+    /// Returns the horizontal distance between the start of a line and the end of the passed line prefix.
+    fn prefix_width(&self, ctx: &Context, prefix: &str) -> f32 {
+        // measuring the position of an appended character keeps trailing spaces
+        self.styled_text(&format!("{}|", prefix), self.color)
+            .glyph_positions(ctx)
+            .ok()
+            .and_then(|positions| positions.last().map(|pos| pos.x))
+            .unwrap_or_default()
+    }
+
+    /// Returns the position of the caret at the passed character index relative to the top left corner of the text.
+    fn caret_position(&self, ctx: &Context, char_index: usize) -> Vec2 {
+        let (line, column) = self.line_column(char_index);
+        let line_content: String = self
+            .value
+            .split('\n')
+            .nth(line)
+            .unwrap_or_default()
+            .chars()
+            .take(column)
+            .collect();
+        Vec2::new(
+            self.prefix_width(ctx, &line_content),
+            line as f32 * self.scale,
+        )
+    }
+
+    /// Measures the caret offsets of every line of the current text, if it changed since the last measurement.
+    fn update_offsets(&mut self, ctx: &Context) {
+        if !self.offsets.is_empty() && self.measured == self.value {
+            return;
+        }
+        self.offsets = self
+            .value
+            .split('\n')
+            .map(|line| {
+                (0..=line.chars().count())
+                    .map(|i| self.prefix_width(ctx, &line.chars().take(i).collect::<String>()))
+                    .collect()
+            })
+            .collect();
+        self.measured = self.value.clone();
+    }
+
+    /// Returns the character index closest to the passed position relative to the top left corner of the text.
+    /// Uses the caret offsets measured during the last draw.
+    fn index_at(&self, position: Vec2) -> usize {
+        let line = ((position.y / self.scale).max(0.) as usize)
+            .min(self.value.split('\n').count().saturating_sub(1));
+        let column = match self.offsets.get(line) {
+            Some(offsets) => offsets
+                .iter()
+                .enumerate()
+                .min_by(|(_, a), (_, b)| {
+                    (*a - position.x).abs().total_cmp(&(*b - position.x).abs())
+                })
+                .map(|(i, _)| i)
+                .unwrap_or_default(),
+            // not measured yet, place the caret at the end of the line
+            None => usize::MAX,
+        };
+        self.char_index(line, column)
+    }
+}
+
+impl Default for TextInput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Eq + Hash> ui::UiContent<T> for TextInput {
+    fn to_element_builder(self, id: u32, ctx: &Context) -> ui::UiElementBuilder<T>
+    where
+        Self: Sized + 'static,
+    {
+        let placeholder_width = self.prefix_width(ctx, &self.placeholder);
+        let rows = self.rows;
+        let scale = self.scale;
+        ui::UiElementBuilder::new(id, self)
+            .with_size(
+                ui::Size::Fill(placeholder_width.max(4. * scale), f32::INFINITY),
+                ui::Size::Fixed(rows as f32 * scale),
+            )
+            .with_focusable(true)
+    }
+
+    fn apply_style(&mut self, ctx: &Context, style: &ui::Style, layout: &mut ui::Layout) {
+        if style.font.is_none() && style.text_scale.is_none() {
+            return;
+        }
+        if let Some(font) = &style.font {
+            self.font = Some(font.clone());
+        }
+        if let Some(scale) = style.text_scale {
+            self.scale = scale;
+        }
+        self.offsets.clear();
+        // the element needs to be resized to fit the new text, just as in to_element_builder
+        layout.x_size = ui::Size::Fill(
+            self.prefix_width(ctx, &self.placeholder)
+                .max(4. * self.scale),
+            f32::INFINITY,
+        );
+        layout.y_size = ui::Size::Fixed(self.rows as f32 * self.scale);
+    }
+
+    fn measure(&mut self, measure: &dyn ui::Measure, layout: &mut ui::Layout) {
+        let placeholder_width = measure
+            .text_size(&self.styled_text(&self.placeholder, self.color))
+            .x;
+        layout.x_size = ui::Size::Fill(placeholder_width.max(4. * self.scale), f32::INFINITY);
+        layout.y_size = ui::Size::Fixed(self.rows as f32 * self.scale);
+    }
+
+    fn draw_content(
+        &mut self,
+        ctx: &mut Context,
+        canvas: &mut graphics::Canvas,
+        param: ui::UiDrawParam,
+    ) {
+        let target = param.target;
+        self.update_offsets(ctx);
+
+        // keep the caret within the visible area
+        let caret = self.caret_position(ctx, self.cursor);
+        if caret.x - self.scroll.x > target.w - 2. {
+            self.scroll.x = caret.x - target.w + 2.;
+        } else if caret.x < self.scroll.x {
+            self.scroll.x = caret.x;
+        }
+        if caret.y + self.scale - self.scroll.y > target.h {
+            self.scroll.y = caret.y + self.scale - target.h;
+        } else if caret.y < self.scroll.y {
+            self.scroll.y = caret.y;
+        }
+        let origin = Vec2::new(target.x, target.y) - self.scroll;
+
+        let param = param.clip(target);
+
+        param.draw_clipped(canvas, |canvas| {
+            // selection
+            if let Some((start, end)) = self.selection() {
+                let (start_line, _) = self.line_column(start);
+                let (end_line, _) = self.line_column(end);
+                for line in start_line..=end_line {
+                    let from = if line == start_line {
+                        self.caret_position(ctx, start).x
+                    } else {
+                        0.
+                    };
+                    let to = if line == end_line {
+                        self.caret_position(ctx, end).x
+                    } else {
+                        self.caret_position(ctx, self.char_index(line, usize::MAX))
+                            .x
+                            + self.scale / 4.
+                    };
+                    canvas.draw(
+                        &Quad,
+                        DrawParam::new()
+                            .dest_rect(Rect::new(
+                                origin.x + from,
+                                origin.y + line as f32 * self.scale,
+                                to - from,
+                                self.scale,
+                            ))
+                            .color(param.tinted(self.selection_color))
+                            .z(param.param.z),
+                    );
+                }
+            }
+
+            // text or placeholder
+            let text = if self.value.is_empty() && !self.active {
+                self.styled_text(&self.placeholder, param.tinted(self.placeholder_color))
+            } else {
+                self.styled_text(&self.value, param.tinted(self.color))
+            };
+            canvas.draw(&text, param.param.dest(origin));
+
+            // caret
+            if self.active && self.blink_time < CARET_BLINK {
+                canvas.draw(
+                    &Quad,
+                    DrawParam::new()
+                        .dest_rect(Rect::new(
+                            origin.x + caret.x,
+                            origin.y + caret.y,
+                            (self.scale / 12.).max(1.),
+                            self.scale,
+                        ))
+                        .color(param.tinted(self.color))
+                        .z(param.param.z),
+                );
+            }
+        });
+
+        self.blink_time += ctx.time.delta();
+        if self.blink_time >= 2 * CARET_BLINK {
+            self.blink_time = Duration::ZERO;
+        }
+    }
+
+    fn update_input(
+        &mut self,
+        input: &ui::InputState,
+        target: Rect,
+        transform: Affine2,
+        hovered: bool,
+    ) {
+        // clicking activates the field and places the caret, clicking anywhere else (even if covered by other elements) deactivates it
+        if !input.button_just_pressed(ggez::event::MouseButton::Left) {
+            return;
+        }
+        if hovered {
+            let mouse = transform.inverse().transform_point2(input.mouse_position);
+            let index =
+                self.index_at(Vec2::new(mouse.x - target.x, mouse.y - target.y) + self.scroll);
+            let select = self.active && input.is_mod_active(KeyMods::SHIFT);
+            self.move_cursor(index, select);
+            self.blink_time = Duration::ZERO;
+        } else {
+            self.anchor = None;
+        }
+        self.active = hovered;
+    }
+
+    fn text_input_event(&mut self, character: char) -> bool {
+        if !self.active {
+            return false;
+        }
+        // control characters (backspace, enter, ...) are handled as key presses
+        if !character.is_control() {
+            self.insert(&character.to_string());
+            self.blink_time = Duration::ZERO;
+        }
+        true
+    }
+
+    fn key_down_event(&mut self, input: KeyInput) -> bool {
+        if !self.active {
+            return false;
+        }
+
+        let shift = input.mods.contains(KeyMods::SHIFT);
+        let ctrl = input.mods.contains(KeyMods::CTRL);
+        let (line, column) = self.line_column(self.cursor);
+
+        match input.keycode {
+            Some(KeyCode::Back) => self.delete(false, ctrl),
+            Some(KeyCode::Delete) => self.delete(true, ctrl),
+            Some(KeyCode::Left) => {
+                let position = match self.selection() {
+                    Some((start, _)) if !shift => start,
+                    _ if ctrl => self.word_boundary(false),
+                    _ => self.cursor.saturating_sub(1),
+                };
+                self.move_cursor(position, shift);
+            }
+            Some(KeyCode::Right) => {
+                let position = match self.selection() {
+                    Some((_, end)) if !shift => end,
+                    _ if ctrl => self.word_boundary(true),
+                    _ => self.cursor + 1,
+                };
+                self.move_cursor(position, shift);
+            }
+            Some(KeyCode::Up) if self.multiline => {
+                let position = if line == 0 {
+                    0
+                } else {
+                    self.char_index(line - 1, column)
+                };
+                self.move_cursor(position, shift);
+            }
+            Some(KeyCode::Down) if self.multiline => {
+                let position = self.char_index(line + 1, column);
+                self.move_cursor(position, shift);
+            }
+            Some(KeyCode::Home) => {
+                let position = if ctrl || !self.multiline {
+                    0
+                } else {
+                    self.char_index(line, 0)
+                };
+                self.move_cursor(position, shift);
+            }
+            Some(KeyCode::End) => {
+                let position = if ctrl || !self.multiline {
+                    usize::MAX
+                } else {
+                    self.char_index(line, usize::MAX)
+                };
+                self.move_cursor(position, shift);
+            }
+            Some(KeyCode::A) if ctrl => {
+                self.anchor = Some(0);
+                self.cursor = self.value.chars().count();
+            }
+            Some(KeyCode::Return | KeyCode::NumpadEnter) => {
+                if self.multiline && !ctrl {
+                    self.insert("\n");
+                } else {
+                    self.submitted = true;
+                }
+            }
+            Some(KeyCode::Escape) => {
+                self.set_active(false);
+            }
+            // leaving the field with Tab lets the focus move on to the next element
+            Some(KeyCode::Tab) => {
+                self.set_active(false);
+                return false;
+            }
+            // other keys are left to the rest of the UI and the game
+            _ => return false,
+        }
+
+        self.blink_time = Duration::ZERO;
+        true
+    }
+
+    fn collect_messages(&mut self, id: u32, messages: &mut HashSet<ui::UiMessage<T>>) {
+        if self.changed {
+            messages.insert(ui::UiMessage::TextChanged(id));
+            messages.insert(ui::UiMessage::ValueChanged(
+                id,
+                ui::UiValue::Text(self.value.clone()),
+            ));
+            self.changed = false;
+        }
+        if self.submitted {
+            messages.insert(ui::UiMessage::TextSubmitted(id));
+            self.submitted = false;
+        }
+    }
+
+    fn focus_triggered(&mut self) {
+        self.set_active(true);
+    }
+
+    fn captures_keyboard(&self) -> bool {
+        self.active
+    }
+
+    fn text_value(&self) -> Option<&str> {
+        Some(&self.value)
+    }
+
+    fn value(&self) -> Option<ui::UiValue> {
+        Some(ui::UiValue::Text(self.value.clone()))
+    }
+
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
+
+    fn as_any_mut(&mut self) -> Option<&mut dyn std::any::Any> {
+        Some(self)
+    }
+}
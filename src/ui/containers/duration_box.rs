@@ -83,4 +83,15 @@ impl<T: Copy + Eq + Hash> ui::UiContainer<T> for DurationBox<T> {
             self.child = ui::UiElement::new(0, ());
         }
     }
+
+    fn take_id(&mut self, id: u32) -> Option<ui::UiElement<T>> {
+        if self.child.get_id() == id {
+            Some(std::mem::replace(
+                &mut self.child,
+                ui::UiElement::new(0, ()),
+            ))
+        } else {
+            None
+        }
+    }
 }
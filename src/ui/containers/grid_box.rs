@@ -279,40 +279,11 @@ impl<T: Copy + Eq + Hash> ui::UiContent<T> for GridBox<T> {
         canvas: &mut ggez::graphics::Canvas,
         param: ui::UiDrawParam,
     ) {
-        // get column widths
-        let column_widths = self.get_column_widths(param.target.w);
-        // ... and partial sum
-        let column_widths_ps =
-            column_widths
-                .iter()
-                .fold(Vec::from([param.target.x]), |mut vec, val| {
-                    vec.push(*vec.last().unwrap_or(&0.) + val + self.horizontal_spacing);
-                    vec
-                });
-
-        // get row heights
-        let row_heights = self.get_row_heights(param.target.h);
-        // ... and partial sum
-        let row_heights_ps =
-            row_heights
-                .iter()
-                .fold(Vec::from([param.target.y]), |mut vec, val| {
-                    vec.push(*vec.last().unwrap_or(&0.) + val + self.vertical_spacing);
-                    vec
-                });
+        let targets = ui::UiContainer::get_child_targets(self, param.target);
 
         // actually draw children
-        for (index, element) in self.children.iter_mut().enumerate() {
-            element.draw_to_rectangle(
-                ctx,
-                canvas,
-                param.target(Rect::new(
-                    *column_widths_ps.get(index % self.cols).unwrap_or(&0.),
-                    *row_heights_ps.get(index / self.cols).unwrap_or(&0.),
-                    *column_widths.get(index % self.cols).unwrap_or(&0.),
-                    *row_heights.get(index / self.cols).unwrap_or(&0.),
-                )),
-            );
+        for (element, target) in self.children.iter_mut().zip(targets) {
+            element.draw_to_rectangle(ctx, canvas, param.target(target));
         }
     }
 
@@ -329,8 +300,8 @@ impl<T: Copy + Eq + Hash> ui::UiContainer<T> for GridBox<T> {
     fn content_width_range(&self) -> (f32, f32) {
         self.get_column_ranges().iter().fold(
             (
-                (0.max(self.cols - 1)) as f32 * self.horizontal_spacing,
-                (0.max(self.cols - 1)) as f32 * self.horizontal_spacing,
+                self.cols.saturating_sub(1) as f32 * self.horizontal_spacing,
+                self.cols.saturating_sub(1) as f32 * self.horizontal_spacing,
             ),
             |old, range| (old.0 + range.0, old.1 + range.1),
         )
@@ -339,13 +310,46 @@ impl<T: Copy + Eq + Hash> ui::UiContainer<T> for GridBox<T> {
     fn content_height_range(&self) -> (f32, f32) {
         self.get_row_ranges().iter().fold(
             (
-                (0.max(self.rows - 1)) as f32 * self.vertical_spacing,
-                (0.max(self.rows - 1)) as f32 * self.vertical_spacing,
+                self.rows.saturating_sub(1) as f32 * self.vertical_spacing,
+                self.rows.saturating_sub(1) as f32 * self.vertical_spacing,
             ),
             |old, range| (old.0 + range.0, old.1 + range.1),
         )
     }
 
+    fn get_child_targets(&self, target: Rect) -> Vec<Rect> {
+        // get column widths
+        let column_widths = self.get_column_widths(target.w);
+        // ... and partial sum
+        let column_widths_ps = column_widths
+            .iter()
+            .fold(Vec::from([target.x]), |mut vec, val| {
+                vec.push(*vec.last().unwrap_or(&0.) + val + self.horizontal_spacing);
+                vec
+            });
+
+        // get row heights
+        let row_heights = self.get_row_heights(target.h);
+        // ... and partial sum
+        let row_heights_ps = row_heights
+            .iter()
+            .fold(Vec::from([target.y]), |mut vec, val| {
+                vec.push(*vec.last().unwrap_or(&0.) + val + self.vertical_spacing);
+                vec
+            });
+
+        (0..self.children.len())
+            .map(|index| {
+                Rect::new(
+                    *column_widths_ps.get(index % self.cols).unwrap_or(&0.),
+                    *row_heights_ps.get(index / self.cols).unwrap_or(&0.),
+                    *column_widths.get(index % self.cols).unwrap_or(&0.),
+                    *row_heights.get(index / self.cols).unwrap_or(&0.),
+                )
+            })
+            .collect()
+    }
+
     fn get_children(&self) -> &[ui::UiElement<T>] {
         &self.children
     }
@@ -373,4 +377,11 @@ impl<T: Copy + Eq + Hash> ui::UiContainer<T> for GridBox<T> {
             }
         }
     }
+
+    fn take_id(&mut self, id: u32) -> Option<ui::UiElement<T>> {
+        self.children
+            .iter_mut()
+            .find(|child| child.get_id() == id)
+            .map(|child| std::mem::replace(child, ui::UiElement::new(0, ())))
+    }
 }
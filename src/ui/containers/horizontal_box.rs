@@ -126,23 +126,11 @@ impl<T: Copy + Eq + Hash> ui::UiContent<T> for HorizontalBox<T> {
         canvas: &mut ggez::graphics::Canvas,
         param: ui::UiDrawParam,
     ) {
-        // get calculate vector of dynamically allocated total heights for each element
+        let targets = ui::UiContainer::get_child_targets(self, param.target);
 
-        let dyn_width = self.get_element_widths(param.target.w);
-        let mut x = param.target.x;
         // draw subelements
-        for (element, ele_dyn_width) in self.children.iter_mut().zip(dyn_width) {
-            element.draw_to_rectangle(
-                ctx,
-                canvas,
-                param.target(Rect {
-                    x,
-                    y: param.target.y,
-                    w: ele_dyn_width,
-                    h: param.target.h,
-                }),
-            );
-            x += ele_dyn_width + self.spacing;
+        for (element, target) in self.children.iter_mut().zip(targets) {
+            element.draw_to_rectangle(ctx, canvas, param.target(target));
         }
     }
 
@@ -185,6 +173,28 @@ impl<T: Copy + Hash + Eq> ui::UiContainer<T> for HorizontalBox<T> {
             })
     }
 
+    fn get_child_targets(&self, target: Rect) -> Vec<Rect> {
+        // get calculate vector of dynamically allocated total widths for each element
+
+        let dyn_width = self.get_element_widths(target.w);
+
+        let mut x = target.x;
+
+        dyn_width
+            .into_iter()
+            .map(|ele_dyn_width| {
+                let rect = Rect {
+                    x,
+                    y: target.y,
+                    w: ele_dyn_width,
+                    h: target.h,
+                };
+                x += ele_dyn_width + self.spacing;
+                rect
+            })
+            .collect()
+    }
+
     fn get_children(&self) -> &[ui::UiElement<T>] {
         &self.children
     }
@@ -204,4 +214,11 @@ impl<T: Copy + Hash + Eq> ui::UiContainer<T> for HorizontalBox<T> {
     fn remove_id(&mut self, id: u32) {
         self.children.retain(|child| child.get_id() != id);
     }
+
+    fn take_id(&mut self, id: u32) -> Option<ui::UiElement<T>> {
+        self.children
+            .iter()
+            .position(|child| child.get_id() == id)
+            .map(|index| self.children.remove(index))
+    }
 }
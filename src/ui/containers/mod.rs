@@ -4,6 +4,8 @@ mod duration_box;
 mod grid_box;
 /// Contains the [HorizontalBox] struct.
 mod horizontal_box;
+/// Contains the [ScrollBox] struct.
+mod scroll_box;
 /// Contains the [StackBox] struct.
 mod stack_box;
 /// Contains the [VerticalBox] struct.
@@ -16,6 +18,8 @@ pub use grid_box::GridBox;
 /// Basic container element.
 pub use horizontal_box::HorizontalBox;
 /// Basic container element.
+pub use scroll_box::ScrollBox;
+/// Basic container element.
 pub use stack_box::StackBox;
 /// Basic container element.
 pub use vertical_box::VerticalBox;
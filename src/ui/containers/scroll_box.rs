@@ -0,0 +1,399 @@
+use ggez::{
+    glam::{Affine2, Vec2},
+    graphics::{Color, Rect},
+};
+
+use crate::ui;
+use std::{hash::Hash, slice};
+
+/// The axis a [ScrollBox] scrollbar is currently being dragged along.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ScrollAxis {
+    /// The vertical scrollbar on the right edge of the box.
+    Vertical,
+    /// The horizontal scrollbar on the bottom edge of the box.
+    Horizontal,
+}
+
+/// A box that wraps a single element and displays it at its full (minimum) size, even if that is larger than the area available to the box.
+/// Only the part of the element currently visible through the box (the viewport) is drawn.
+/// The visible part can be moved by using the mouse wheel (hold shift or use a horizontal wheel to scroll horizontally), by dragging the scrollbars or by setting the scroll offset directly.
+/// Mouse wheel events are not part of the ggez [ggez::Context], so remember to forward them to your UI via [ui::UiElement::mouse_wheel_event] (the [crate::scene_manager::SceneManager] forwards them to your [crate::scene_manager::Scene]).
+pub struct ScrollBox<T: Copy + Eq + Hash> {
+    /// The element displayed within this box.
+    child: ui::UiElement<T>,
+    /// The current scroll offset in pixels. (0., 0.) displays the top left corner of the child.
+    offset: Vec2,
+    /// The maximum scroll offset as calculated in the last frame.
+    max_offset: Vec2,
+    /// Wether this box allows scrolling in vertical direction.
+    pub vertical: bool,
+    /// Wether this box allows scrolling in horizontal direction.
+    pub horizontal: bool,
+    /// The amount of pixels scrolled per line of mouse wheel movement.
+    pub scroll_speed: f32,
+    /// The width (or height, for the horizontal bar) of the scrollbars.
+    pub scrollbar_width: f32,
+    /// The visuals of the draggable part of the scrollbars.
+    pub thumb_visuals: ui::Visuals,
+    /// The visuals of the background of the scrollbars.
+    pub track_visuals: ui::Visuals,
+    /// The area the child was visible through in the last frame.
+    viewport: Rect,
+    /// The transformation from the coordinates of the viewport to screen coordinates in the last frame.
+    transform: Affine2,
+    /// The scrollbar currently being dragged (if any) and the distance between the mouse cursor and the start of the thumb.
+    drag: Option<(ScrollAxis, f32)>,
+    /// Wether the mouse cursor was above the viewport during the last update.
+    viewport_hovered: bool,
+}
+
+impl<T: Copy + Eq + Hash> ScrollBox<T> {
+    /// Creates a new ScrollBox containing the passed element that scrolls in vertical direction only.
+    pub fn new(element: ui::UiElement<T>) -> Self {
+        Self {
+            child: element,
+            offset: Vec2::ZERO,
+            max_offset: Vec2::ZERO,
+            vertical: true,
+            horizontal: false,
+            scroll_speed: 20.,
+            scrollbar_width: 8.,
+            thumb_visuals: ui::Visuals::new(
+                Color::from_rgba(160, 160, 160, 200),
+                Color::from_rgba(0, 0, 0, 0),
+                0.,
+                3.,
+            ),
+            track_visuals: ui::Visuals::new(
+                Color::from_rgba(40, 40, 40, 100),
+                Color::from_rgba(0, 0, 0, 0),
+                0.,
+                3.,
+            ),
+            viewport: Rect::default(),
+            transform: Affine2::IDENTITY,
+            drag: None,
+            viewport_hovered: false,
+        }
+    }
+
+    /// Creates a new ScrollBox containing the passed element that scrolls in both horizontal and vertical direction.
+    pub fn new_bidirectional(element: ui::UiElement<T>) -> Self {
+        Self {
+            horizontal: true,
+            ..Self::new(element)
+        }
+    }
+
+    /// Creates a new ScrollBox containing the passed element that scrolls in horizontal direction only.
+    pub fn new_horizontal(element: ui::UiElement<T>) -> Self {
+        Self {
+            horizontal: true,
+            vertical: false,
+            ..Self::new(element)
+        }
+    }
+
+    /// Returns the current scroll offset in pixels.
+    pub fn get_scroll(&self) -> Vec2 {
+        self.offset
+    }
+
+    /// Returns the maximum scroll offset in pixels, as calculated when this box was last drawn.
+    pub fn get_max_scroll(&self) -> Vec2 {
+        self.max_offset
+    }
+
+    /// Sets the scroll offset in pixels. The offset will be clamped to the valid range the next time this box is drawn.
+    pub fn set_scroll(&mut self, offset: Vec2) {
+        self.offset = offset;
+    }
+
+    /// Moves the scroll offset by the passed amount of pixels. The offset will be clamped to the valid range the next time this box is drawn.
+    pub fn scroll_by(&mut self, delta: Vec2) {
+        self.offset += delta;
+    }
+
+    /// Returns the rectangles of the (track, thumb) of the scrollbar of the given axis, if that bar is currently displayed.
+    fn scrollbar_rects(&self, axis: ScrollAxis) -> Option<(Rect, Rect)> {
+        let vp = self.viewport;
+        match axis {
+            ScrollAxis::Vertical if self.vertical && self.max_offset.y > 0. => {
+                let track = Rect::new(vp.x + vp.w, vp.y, self.scrollbar_width, vp.h);
+                let thumb_h =
+                    (vp.h * vp.h / (vp.h + self.max_offset.y)).max(self.scrollbar_width.min(vp.h));
+                let thumb_y = track.y + (vp.h - thumb_h) * self.offset.y / self.max_offset.y;
+                Some((
+                    track,
+                    Rect::new(track.x, thumb_y, self.scrollbar_width, thumb_h),
+                ))
+            }
+            ScrollAxis::Horizontal if self.horizontal && self.max_offset.x > 0. => {
+                let track = Rect::new(vp.x, vp.y + vp.h, vp.w, self.scrollbar_width);
+                let thumb_w =
+                    (vp.w * vp.w / (vp.w + self.max_offset.x)).max(self.scrollbar_width.min(vp.w));
+                let thumb_x = track.x + (vp.w - thumb_w) * self.offset.x / self.max_offset.x;
+                Some((
+                    track,
+                    Rect::new(thumb_x, track.y, thumb_w, self.scrollbar_width),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the viewport and the size of the child if this box is drawn to the passed target.
+    fn get_viewport(&self, target: Rect) -> (Rect, Vec2) {
+        // find out which scrollbars are needed. The vertical bar may take up the space that makes the horizontal bar neccessary and vice versa.
+        let (min_w, min_h) = (self.child.width_range().0, self.child.height_range().0);
+        let mut bar_v = self.vertical && min_h > target.h;
+        let bar_h =
+            self.horizontal && min_w > target.w - if bar_v { self.scrollbar_width } else { 0. };
+        bar_v = bar_v || (self.vertical && bar_h && min_h > target.h - self.scrollbar_width);
+
+        // the viewport is the target minus any space taken up by scrollbars
+        let viewport = Rect::new(
+            target.x,
+            target.y,
+            (target.w - if bar_v { self.scrollbar_width } else { 0. }).max(0.),
+            (target.h - if bar_h { self.scrollbar_width } else { 0. }).max(0.),
+        );
+
+        // the child receives its full size in all scrollable directions
+        let child_w = if self.horizontal {
+            min_w.max(viewport.w)
+        } else {
+            viewport.w
+        };
+        let child_h = if self.vertical {
+            min_h.max(viewport.h)
+        } else {
+            viewport.h
+        };
+
+        (viewport, Vec2::new(child_w, child_h))
+    }
+
+    /// Updates viewport, maximum scroll offset and transformation for the passed target and clamps the scroll offset to the valid range.
+    /// Returns the size of the child.
+    fn update_viewport(&mut self, target: Rect, transform: Affine2) -> Vec2 {
+        let (viewport, child_size) = self.get_viewport(target);
+        self.viewport = viewport;
+        self.transform = transform;
+        self.max_offset = (child_size - Vec2::new(viewport.w, viewport.h)).max(Vec2::ZERO);
+        self.offset = self.offset.clamp(Vec2::ZERO, self.max_offset);
+        child_size
+    }
+
+    /// Returns the mouse position of the passed input state in the coordinates of the viewport.
+    fn local_mouse(&self, input: &ui::InputState) -> Vec2 {
+        self.transform
+            .inverse()
+            .transform_point2(input.mouse_position)
+    }
+
+    /// Starts, continues or ends dragging of the scrollbars based on the passed input state.
+    /// Clicking the track outside of the thumb moves the thumb to the cursor and starts dragging from there.
+    fn handle_drag(&mut self, input: &ui::InputState) {
+        let mouse = self.local_mouse(input);
+
+        if input.button_just_pressed(ggez::event::MouseButton::Left) {
+            for axis in [ScrollAxis::Vertical, ScrollAxis::Horizontal] {
+                if let Some((track, thumb)) = self.scrollbar_rects(axis) {
+                    if thumb.contains(mouse) {
+                        self.drag = Some(match axis {
+                            ScrollAxis::Vertical => (axis, mouse.y - thumb.y),
+                            ScrollAxis::Horizontal => (axis, mouse.x - thumb.x),
+                        });
+                    } else if track.contains(mouse) {
+                        self.drag = Some(match axis {
+                            ScrollAxis::Vertical => (axis, thumb.h / 2.),
+                            ScrollAxis::Horizontal => (axis, thumb.w / 2.),
+                        });
+                    }
+                }
+            }
+        }
+
+        if !input.button_pressed(ggez::event::MouseButton::Left) {
+            self.drag = None;
+        }
+
+        if let Some((axis, grab)) = self.drag {
+            if let Some((track, thumb)) = self.scrollbar_rects(axis) {
+                match axis {
+                    ScrollAxis::Vertical => {
+                        let free = (track.h - thumb.h).max(f32::EPSILON);
+                        self.offset.y = ((mouse.y - grab - track.y) / free * self.max_offset.y)
+                            .clamp(0., self.max_offset.y);
+                    }
+                    ScrollAxis::Horizontal => {
+                        let free = (track.w - thumb.w).max(f32::EPSILON);
+                        self.offset.x = ((mouse.x - grab - track.x) / free * self.max_offset.x)
+                            .clamp(0., self.max_offset.x);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<T: Copy + Eq + Hash> ui::UiContent<T> for ScrollBox<T> {
+    fn to_element_builder(self, id: u32, _ctx: &ggez::Context) -> ui::UiElementBuilder<T>
+    where
+        Self: Sized + 'static,
+    {
+        ui::UiElementBuilder::new(id, self)
+            .as_fill()
+            .with_padding((0., 0., 0., 0.))
+    }
+
+    fn draw_content(
+        &mut self,
+        ctx: &mut ggez::Context,
+        canvas: &mut ggez::graphics::Canvas,
+        param: ui::UiDrawParam,
+    ) {
+        let child_size = self.update_viewport(param.target, param.transform);
+
+        // draw the child, clipped to the viewport (and any clipping already active)
+        let mouse_listen = param.mouse_listen && self.viewport_hovered;
+        self.child.draw_to_rectangle(
+            ctx,
+            canvas,
+            param
+                .target(Rect::new(
+                    self.viewport.x - self.offset.x,
+                    self.viewport.y - self.offset.y,
+                    child_size.x,
+                    child_size.y,
+                ))
+                .clip(self.viewport)
+                .mouse_listen(mouse_listen),
+        );
+
+        // draw the scrollbars
+        for axis in [ScrollAxis::Vertical, ScrollAxis::Horizontal] {
+            if let Some((track, thumb)) = self.scrollbar_rects(axis) {
+                self.track_visuals.draw(ctx, canvas, param.target(track));
+                self.thumb_visuals.draw(ctx, canvas, param.target(thumb));
+            }
+        }
+    }
+
+    fn update_input(
+        &mut self,
+        input: &ui::InputState,
+        target: Rect,
+        transform: Affine2,
+        hovered: bool,
+    ) {
+        self.update_viewport(target, transform);
+        self.viewport_hovered = hovered && self.viewport.contains(self.local_mouse(input));
+
+        // a drag that has already started continues even if the mouse leaves the box
+        if hovered || self.drag.is_some() {
+            self.handle_drag(input);
+        }
+    }
+
+    fn mouse_wheel_event(&mut self, input: &ui::InputState, x: f32, y: f32) -> bool {
+        if !self.viewport.contains(self.local_mouse(input))
+            || (self.max_offset.x <= 0. && self.max_offset.y <= 0.)
+        {
+            return false;
+        }
+
+        // vertical wheel movement scrolls horizontally if shift is held or there is nothing to scroll vertically
+        let (x, y) = if input.is_mod_active(ggez::input::keyboard::KeyMods::SHIFT)
+            || self.max_offset.y <= 0.
+        {
+            (x + y, 0.)
+        } else {
+            (x, y)
+        };
+
+        self.scroll_by(Vec2::new(-x, -y) * self.scroll_speed);
+        true
+    }
+
+    fn container(&self) -> Option<&dyn ui::UiContainer<T>> {
+        Some(self)
+    }
+
+    fn container_mut(&mut self) -> Option<&mut dyn ui::UiContainer<T>> {
+        Some(self)
+    }
+}
+
+impl<T: Copy + Eq + Hash> ui::UiContainer<T> for ScrollBox<T> {
+    fn content_width_range(&self) -> (f32, f32) {
+        let (min, max) = self.child.width_range();
+        let bar = if self.vertical {
+            self.scrollbar_width
+        } else {
+            0.
+        };
+        (if self.horizontal { bar } else { min + bar }, max + bar)
+    }
+
+    fn content_height_range(&self) -> (f32, f32) {
+        let (min, max) = self.child.height_range();
+        let bar = if self.horizontal {
+            self.scrollbar_width
+        } else {
+            0.
+        };
+        (if self.vertical { bar } else { min + bar }, max + bar)
+    }
+
+    fn get_child_targets(&self, target: Rect) -> Vec<Rect> {
+        let (viewport, child_size) = self.get_viewport(target);
+        let offset = self.offset.clamp(
+            Vec2::ZERO,
+            (child_size - Vec2::new(viewport.w, viewport.h)).max(Vec2::ZERO),
+        );
+        vec![Rect::new(
+            viewport.x - offset.x,
+            viewport.y - offset.y,
+            child_size.x,
+            child_size.y,
+        )]
+    }
+
+    fn get_children(&self) -> &[ui::UiElement<T>] {
+        slice::from_ref(&self.child)
+    }
+
+    fn get_children_mut(&mut self) -> &mut [ui::UiElement<T>] {
+        slice::from_mut(&mut self.child)
+    }
+
+    fn add(&mut self, element: ui::UiElement<T>) {
+        self.child = element;
+    }
+
+    fn remove_expired(&mut self) {
+        if self.child.expired() {
+            self.child = ui::UiElement::new(0, ());
+        }
+    }
+
+    fn remove_id(&mut self, id: u32) {
+        if self.child.get_id() == id {
+            self.child = ui::UiElement::new(0, ());
+        }
+    }
+
+    fn take_id(&mut self, id: u32) -> Option<ui::UiElement<T>> {
+        if self.child.get_id() == id {
+            Some(std::mem::replace(
+                &mut self.child,
+                ui::UiElement::new(0, ()),
+            ))
+        } else {
+            None
+        }
+    }
+}
@@ -63,6 +63,10 @@ impl<T: Copy + Eq + Hash> ui::UiContent<T> for StackBox<T> {
 }
 
 impl<T: Copy + Eq + Hash> ui::UiContainer<T> for StackBox<T> {
+    fn reverse_draw_order(&self) -> bool {
+        true
+    }
+
     fn content_width_range(&self) -> (f32, f32) {
         // maximum of all min widths and minimum of all max widths, as all elements are layed out in parallel x direction
 
@@ -108,4 +112,11 @@ impl<T: Copy + Eq + Hash> ui::UiContainer<T> for StackBox<T> {
     fn remove_id(&mut self, id: u32) {
         self.children.retain(|child| child.get_id() != id);
     }
+
+    fn take_id(&mut self, id: u32) -> Option<ui::UiElement<T>> {
+        self.children
+            .iter()
+            .position(|child| child.get_id() == id)
+            .map(|index| self.children.remove(index))
+    }
 }
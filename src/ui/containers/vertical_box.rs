@@ -126,25 +126,11 @@ impl<T: Copy + Eq + Hash> ui::UiContent<T> for VerticalBox<T> {
         canvas: &mut ggez::graphics::Canvas,
         param: ui::UiDrawParam,
     ) {
-        // get calculate vector of dynamically allocated total heights for each element
-
-        let dyn_height = self.get_element_heights(param.target.h);
-
-        let mut y = param.target.y;
+        let targets = ui::UiContainer::get_child_targets(self, param.target);
 
         // draw subelements
-        for (element, ele_dyn_height) in self.children.iter_mut().zip(dyn_height) {
-            element.draw_to_rectangle(
-                ctx,
-                canvas,
-                param.target(Rect {
-                    x: param.target.x,
-                    y,
-                    w: param.target.w,
-                    h: ele_dyn_height,
-                }),
-            );
-            y += ele_dyn_height + self.spacing;
+        for (element, target) in self.children.iter_mut().zip(targets) {
+            element.draw_to_rectangle(ctx, canvas, param.target(target));
         }
     }
 
@@ -188,6 +174,28 @@ impl<T: Copy + Eq + Hash> ui::UiContainer<T> for VerticalBox<T> {
         )
     }
 
+    fn get_child_targets(&self, target: Rect) -> Vec<Rect> {
+        // get calculate vector of dynamically allocated total heights for each element
+
+        let dyn_height = self.get_element_heights(target.h);
+
+        let mut y = target.y;
+
+        dyn_height
+            .into_iter()
+            .map(|ele_dyn_height| {
+                let rect = Rect {
+                    x: target.x,
+                    y,
+                    w: target.w,
+                    h: ele_dyn_height,
+                };
+                y += ele_dyn_height + self.spacing;
+                rect
+            })
+            .collect()
+    }
+
     fn get_children(&self) -> &[ui::UiElement<T>] {
         &self.children
     }
@@ -207,4 +215,11 @@ impl<T: Copy + Eq + Hash> ui::UiContainer<T> for VerticalBox<T> {
     fn remove_id(&mut self, id: u32) {
         self.children.retain(|child| child.get_id() != id);
     }
+
+    fn take_id(&mut self, id: u32) -> Option<ui::UiElement<T>> {
+        self.children
+            .iter()
+            .position(|child| child.get_id() == id)
+            .map(|index| self.children.remove(index))
+    }
 }
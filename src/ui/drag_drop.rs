@@ -0,0 +1,61 @@
+use std::hash::Hash;
+
+use ggez::glam::Vec2;
+
+use super::UiElement;
+
+impl<T: Copy + Eq + Hash> UiElement<T> {
+    /// Returns wether this element carries a drag payload and is currently being dragged.
+    pub(crate) fn is_dragged(&self) -> bool {
+        self.drag_payload.is_some() && self.mouse_state.is_dragging()
+    }
+
+    /// Returns the offset this element is currently drawn at relative to its layout position because it is being dragged, if it is.
+    /// Uses the mouse position of the last update.
+    pub(crate) fn drag_offset(&self) -> Option<Vec2> {
+        if self.drag_payload.is_some() {
+            self.mouse_state.drag_offset(self.mouse_position)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the ID and payload of the element among this element and its successors that is currently being dragged, if any.
+    pub(crate) fn active_drag(&self) -> Option<(u32, T)> {
+        if self.is_dragged() {
+            return self.drag_payload.map(|payload| (self.id, payload));
+        }
+        self.content.container().and_then(|cont| {
+            cont.get_children()
+                .iter()
+                .find_map(|child| child.active_drag())
+        })
+    }
+
+    /// Marks the innermost element below the mouse cursor that accepts the passed payload as hovered drop target and unmarks all others.
+    /// Pass None if nothing is being dragged.
+    /// Returns the ID of the marked element, if it is this element or one of its successors.
+    pub(crate) fn mark_drop_target(&mut self, payload: Option<&T>) -> Option<u32> {
+        let mut target = None;
+        if let Some(cont) = self.content.container_mut() {
+            for child in cont.get_children_mut() {
+                target = target.or(child.mark_drop_target(payload));
+            }
+        }
+
+        self.drop_hovered = target.is_none()
+            && self.mouse_hit
+            && self.id != 0
+            && payload.is_some_and(|payload| {
+                self.drop_filter
+                    .as_ref()
+                    .is_some_and(|accepts| accepts(payload))
+            });
+
+        if self.drop_hovered {
+            Some(self.id)
+        } else {
+            target
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use ggez::graphics::Rect;
+use ggez::{
+    glam::{Affine2, Vec2},
+    graphics::Rect,
+};
 
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 /// This struct remembers the rects a UiElement was drawn to and holds a bool that returns wether or not it can be drawn to those rects again.
@@ -14,5 +17,41 @@ pub enum DrawCache {
         inner: Rect,
         /// The rectangle this elements received as a target area last frame.
         target: Rect,
+        /// The rectangle (in screen coordinates) this element was clipped to last frame, if any.
+        clip: Option<Rect>,
+        /// The transformation from the coordinates of the outer and inner rectangles to screen coordinates last frame.
+        transform: Affine2,
+        /// The z-level this element was drawn at last frame.
+        z: i32,
     },
 }
+
+impl DrawCache {
+    /// Returns wether the passed point (in screen coordinates) is within the visible part of the (transformed) outer rectangle of this cache.
+    /// Always returns false for an invalid cache.
+    pub fn contains(&self, point: impl Into<Vec2>) -> bool {
+        match self {
+            DrawCache::Invalid => false,
+            DrawCache::Valid {
+                outer,
+                clip,
+                transform,
+                ..
+            } => {
+                let point = point.into();
+                outer.contains(transform.inverse().transform_point2(point))
+                    && clip.is_none_or(|clip| clip.contains(point))
+            }
+        }
+    }
+
+    /// Returns the center of the (transformed) outer rectangle of this cache in screen coordinates, or None for an invalid cache.
+    pub fn center(&self) -> Option<Vec2> {
+        match self {
+            DrawCache::Invalid => None,
+            DrawCache::Valid {
+                outer, transform, ..
+            } => Some(transform.transform_point2(outer.center().into())),
+        }
+    }
+}
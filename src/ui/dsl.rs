@@ -0,0 +1,191 @@
+/// Declaratively builds a tree of [UiElement](crate::ui::UiElement)s.
+/// Expands to the same [UiContent::to_element_builder](crate::ui::UiContent::to_element_builder) and [UiElementBuilder](crate::ui::UiElementBuilder) calls you would write by hand
+/// and evaluates to a `GameResult<UiElement<T>>`, as loading images or filling grids may fail.
+/// ### Syntax
+/// The first argument is a `&Context`, the second the root element. Elements are written as `kind(arguments, properties) { children }`:
+/// * `empty(..)`, `text(string, ..)`, `image(path, ..)` create basic elements.
+/// * `content(content, ..)` wraps any other [UiContent](crate::ui::UiContent), `element(element, ..)` embeds an already built element.
+/// * `vbox(..) { .. }`, `hbox(..) { .. }` and `stack(..) { .. }` create containers holding the comma-separated children in braces.
+/// * `grid(columns, rows, ..) { .. }` creates a grid that is filled row by row. Use `empty()` to skip a cell.
+///
+/// Containers may set `spacing = value` (`spacing = (horizontal, vertical)` for grids) as their first property.
+/// All other properties are written as `name = value` and map to the builder function `with_name`, e.g. `id = 1`, `visuals = vis` or `trigger_key = VirtualKeyCode::A`.
+/// Exceptions are `offset`, `alignment`, `size` and `scale` taking a tuple `(x, y)`, `theme` (a `&Theme`, the context is passed automatically),
+/// `tooltip` taking another element, `class` taking a string or a bare identifier, and the flags `fill` and `shrink` without value.
+/// Unknown properties and elements are compile errors.
+/// ```
+/// use ggez::{winit::event::VirtualKeyCode, Context, GameResult};
+/// use mooeye::ui;
+///
+/// fn build_menu(ctx: &Context, vis: ui::Visuals) -> GameResult<ui::UiElement<()>> {
+///     let gui = mooeye::ui!(ctx, vbox(spacing = 6., visuals = vis) {
+///         text("Back", id = 1, class = button, tooltip = text("Return to the menu.")),
+///         grid(2, 2, spacing = (4., 4.)) {
+///             image("/moo.png", scale = (4., 4.)),
+///             empty(),
+///             text("Quit", id = 2, trigger_key = VirtualKeyCode::Escape),
+///         },
+///     })?;
+///     Ok(gui)
+/// }
+/// ```
+/// Unknown elements ...
+/// ```compile_fail
+/// # fn build(ctx: &ggez::Context) -> ggez::GameResult<mooeye::ui::UiElement<()>> {
+/// mooeye::ui!(ctx, vbox() { slider(0., 1.) })
+/// # }
+/// ```
+/// ... and unknown properties do not compile.
+/// ```compile_fail
+/// # fn build(ctx: &ggez::Context) -> ggez::GameResult<mooeye::ui::UiElement<()>> {
+/// mooeye::ui!(ctx, text("Moo", colour = ggez::graphics::Color::RED))
+/// # }
+/// ```
+#[macro_export]
+macro_rules! ui {
+    // --- elements ---
+    (@element $ctx:ident; empty($($props:tt)*)) => {
+        $crate::ui!(@props $ctx, $crate::ui::UiContent::to_element_builder((), 0, $ctx); $($props)*).build()
+    };
+    (@element $ctx:ident; text($text:expr $(, $($props:tt)*)?)) => {
+        $crate::ui!(@props $ctx, $crate::ui::UiContent::to_element_builder($crate::ggez::graphics::Text::new($text), 0, $ctx); $($($props)*)?).build()
+    };
+    (@element $ctx:ident; image($path:expr $(, $($props:tt)*)?)) => {
+        $crate::ui!(@props $ctx, $crate::ui::UiContent::to_element_builder($crate::ggez::graphics::Image::from_path($ctx, $path)?, 0, $ctx); $($($props)*)?).build()
+    };
+    (@element $ctx:ident; content($content:expr $(, $($props:tt)*)?)) => {
+        $crate::ui!(@props $ctx, $crate::ui::UiContent::to_element_builder($content, 0, $ctx); $($($props)*)?).build()
+    };
+    (@element $ctx:ident; element($element:expr $(, $($props:tt)*)?)) => {
+        $crate::ui!(@props $ctx, $crate::ui::UiElementBuilder::from($element); $($($props)*)?).build()
+    };
+    (@element $ctx:ident; vbox(spacing = $spacing:expr $(, $($props:tt)*)?) { $($children:tt)* }) => {
+        $crate::ui!(@container $ctx; $crate::ui::containers::VerticalBox::new_spaced($spacing); [$($($props)*)?] { $($children)* })
+    };
+    (@element $ctx:ident; vbox($($props:tt)*) { $($children:tt)* }) => {
+        $crate::ui!(@container $ctx; $crate::ui::containers::VerticalBox::new(); [$($props)*] { $($children)* })
+    };
+    (@element $ctx:ident; hbox(spacing = $spacing:expr $(, $($props:tt)*)?) { $($children:tt)* }) => {
+        $crate::ui!(@container $ctx; $crate::ui::containers::HorizontalBox::new_spaced($spacing); [$($($props)*)?] { $($children)* })
+    };
+    (@element $ctx:ident; hbox($($props:tt)*) { $($children:tt)* }) => {
+        $crate::ui!(@container $ctx; $crate::ui::containers::HorizontalBox::new(); [$($props)*] { $($children)* })
+    };
+    (@element $ctx:ident; stack($($props:tt)*) { $($children:tt)* }) => {
+        $crate::ui!(@container $ctx; $crate::ui::containers::StackBox::new(); [$($props)*] { $($children)* })
+    };
+    (@element $ctx:ident; grid($columns:expr, $rows:expr, spacing = ($horizontal:expr, $vertical:expr) $(, $($props:tt)*)?) { $($children:tt)* }) => {
+        $crate::ui!(@grid $ctx; $columns, $rows, $horizontal, $vertical; [$($($props)*)?] { $($children)* })
+    };
+    (@element $ctx:ident; grid($columns:expr, $rows:expr, spacing = $spacing:expr $(, $($props:tt)*)?) { $($children:tt)* }) => {
+        $crate::ui!(@grid $ctx; $columns, $rows, $spacing, $spacing; [$($($props)*)?] { $($children)* })
+    };
+    (@element $ctx:ident; grid($columns:expr, $rows:expr $(, $($props:tt)*)?) { $($children:tt)* }) => {
+        $crate::ui!(@grid $ctx; $columns, $rows, 5., 5.; [$($($props)*)?] { $($children)* })
+    };
+    (@element $ctx:ident; $kind:ident $($rest:tt)*) => {
+        compile_error!(concat!("Unknown element `", stringify!($kind), "` or invalid arguments in mooeye::ui!. Expected one of empty, text, image, content, element, vbox, hbox, stack, grid."))
+    };
+
+    // --- containers ---
+    (@container $ctx:ident; $content:expr; [$($props:tt)*] { $($kind:ident ($($args:tt)*) $({ $($children:tt)* })?),* $(,)? }) => {{
+        let mut container = $content;
+        $(
+            $crate::ui::UiContainer::add(&mut container, $crate::ui!(@element $ctx; $kind($($args)*) $({ $($children)* })?));
+        )*
+        $crate::ui!(@props $ctx, $crate::ui::UiContent::to_element_builder(container, 0, $ctx); $($props)*).build()
+    }};
+    (@grid $ctx:ident; $columns:expr, $rows:expr, $horizontal:expr, $vertical:expr; [$($props:tt)*] { $($kind:ident ($($args:tt)*) $({ $($children:tt)* })?),* $(,)? }) => {{
+        let (columns, rows): (usize, usize) = ($columns, $rows);
+        let mut grid = $crate::ui::containers::GridBox::new_spaced(columns, rows, $horizontal, $vertical);
+        // cells are filled row by row, children beyond the last cell are rejected by the grid
+        #[allow(unused_mut, unused_variables)]
+        let mut cells = (0..rows).flat_map(|y| (0..columns).map(move |x| (x, y)));
+        $(
+            let (x, y) = cells.next().unwrap_or((columns, rows));
+            grid.add($crate::ui!(@element $ctx; $kind($($args)*) $({ $($children)* })?), x, y)?;
+        )*
+        $crate::ui!(@props $ctx, $crate::ui::UiContent::to_element_builder(grid, 0, $ctx); $($props)*).build()
+    }};
+
+    // --- properties ---
+    (@props $ctx:ident, $builder:expr;) => {
+        $builder
+    };
+    (@props $ctx:ident, $builder:expr; class = $class:ident $(, $($rest:tt)*)?) => {
+        $crate::ui!(@props $ctx, $builder.with_class(stringify!($class)); $($($rest)*)?)
+    };
+    (@props $ctx:ident, $builder:expr; tooltip = $kind:ident ($($args:tt)*) $({ $($children:tt)* })? $(, $($rest:tt)*)?) => {
+        $crate::ui!(@props $ctx, $builder.with_tooltip($crate::ui!(@element $ctx; $kind($($args)*) $({ $($children)* })?)); $($($rest)*)?)
+    };
+    (@props $ctx:ident, $builder:expr; $name:ident = ($x:expr, $y:expr) $(, $($rest:tt)*)?) => {
+        $crate::ui!(@props $ctx, $crate::ui!(@pair $ctx, $builder, $name, $x, $y); $($($rest)*)?)
+    };
+    (@props $ctx:ident, $builder:expr; spacing = $($rest:tt)*) => {
+        compile_error!("`spacing` can only be set on containers and must be their first property in mooeye::ui!.")
+    };
+    (@props $ctx:ident, $builder:expr; fill $(, $($rest:tt)*)?) => {
+        $crate::ui!(@props $ctx, $builder.as_fill(); $($($rest)*)?)
+    };
+    (@props $ctx:ident, $builder:expr; shrink $(, $($rest:tt)*)?) => {
+        $crate::ui!(@props $ctx, $builder.as_shrink(); $($($rest)*)?)
+    };
+    (@props $ctx:ident, $builder:expr; $name:ident = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::ui!(@props $ctx, $crate::ui!(@prop $ctx, $builder, $name, $value); $($($rest)*)?)
+    };
+    (@props $ctx:ident, $builder:expr; $($rest:tt)*) => {
+        compile_error!(concat!("Invalid properties `", stringify!($($rest)*), "` in mooeye::ui!. Expected `name = value`, `fill` or `shrink`."))
+    };
+
+    (@pair $ctx:ident, $builder:expr, offset, $x:expr, $y:expr) => { $builder.with_offset($x, $y) };
+    (@pair $ctx:ident, $builder:expr, alignment, $x:expr, $y:expr) => { $builder.with_alignment($x, $y) };
+    (@pair $ctx:ident, $builder:expr, size, $x:expr, $y:expr) => { $builder.with_size($x, $y) };
+    (@pair $ctx:ident, $builder:expr, scale, $x:expr, $y:expr) => { $builder.scaled($x, $y) };
+    (@pair $ctx:ident, $builder:expr, padding, $x:expr, $y:expr) => {
+        compile_error!("`padding` takes four values (top, right, bottom, left) in mooeye::ui!.")
+    };
+    (@pair $ctx:ident, $builder:expr, $name:ident, $x:expr, $y:expr) => {
+        $crate::ui!(@prop $ctx, $builder, $name, ($x, $y))
+    };
+
+    (@prop $ctx:ident, $builder:expr, id, $value:expr) => { $builder.with_id($value) };
+    (@prop $ctx:ident, $builder:expr, class, $value:expr) => { $builder.with_class($value) };
+    (@prop $ctx:ident, $builder:expr, theme, $value:expr) => { $builder.with_theme($ctx, $value) };
+    (@prop $ctx:ident, $builder:expr, offset, $value:expr) => {{ let (x, y) = $value; $builder.with_offset(x, y) }};
+    (@prop $ctx:ident, $builder:expr, alignment, $value:expr) => {{ let (x, y) = $value; $builder.with_alignment(x, y) }};
+    (@prop $ctx:ident, $builder:expr, size, $value:expr) => {{ let (x, y) = $value; $builder.with_size(x, y) }};
+    (@prop $ctx:ident, $builder:expr, scale, $value:expr) => {{ let (x, y) = $value; $builder.scaled(x, y) }};
+    (@prop $ctx:ident, $builder:expr, tooltip, $value:expr) => { $builder.with_tooltip($value) };
+    (@prop $ctx:ident, $builder:expr, visuals, $value:expr) => { $builder.with_visuals($value) };
+    (@prop $ctx:ident, $builder:expr, hover_visuals, $value:expr) => { $builder.with_hover_visuals($value) };
+    (@prop $ctx:ident, $builder:expr, focus_visuals, $value:expr) => { $builder.with_focus_visuals($value) };
+    (@prop $ctx:ident, $builder:expr, drop_visuals, $value:expr) => { $builder.with_drop_visuals($value) };
+    (@prop $ctx:ident, $builder:expr, opacity, $value:expr) => { $builder.with_opacity($value) };
+    (@prop $ctx:ident, $builder:expr, tint, $value:expr) => { $builder.with_tint($value) };
+    (@prop $ctx:ident, $builder:expr, transform, $value:expr) => { $builder.with_transform($value) };
+    (@prop $ctx:ident, $builder:expr, animation, $value:expr) => { $builder.with_animation($value) };
+    (@prop $ctx:ident, $builder:expr, trigger_sound, $value:expr) => { $builder.with_trigger_sound($value) };
+    (@prop $ctx:ident, $builder:expr, trigger_key, $value:expr) => { $builder.with_trigger_key($value) };
+    (@prop $ctx:ident, $builder:expr, gamepad_button, $value:expr) => { $builder.with_gamepad_button($value) };
+    (@prop $ctx:ident, $builder:expr, focusable, $value:expr) => { $builder.with_focusable($value) };
+    (@prop $ctx:ident, $builder:expr, back, $value:expr) => { $builder.with_back($value) };
+    (@prop $ctx:ident, $builder:expr, drag_payload, $value:expr) => { $builder.with_drag_payload($value) };
+    (@prop $ctx:ident, $builder:expr, drop_target, $value:expr) => { $builder.with_drop_target($value) };
+    (@prop $ctx:ident, $builder:expr, pass_through, $value:expr) => { $builder.with_pass_through($value) };
+    (@prop $ctx:ident, $builder:expr, clip_children, $value:expr) => { $builder.with_clip_children($value) };
+    (@prop $ctx:ident, $builder:expr, message_handler, $value:expr) => { $builder.with_message_handler($value) };
+    (@prop $ctx:ident, $builder:expr, message_handler_mut, $value:expr) => { $builder.with_message_handler_mut($value) };
+    (@prop $ctx:ident, $builder:expr, layout, $value:expr) => { $builder.with_layout($value) };
+    (@prop $ctx:ident, $builder:expr, wrapper_layout, $value:expr) => { $builder.with_wrapper_layout($value) };
+    (@prop $ctx:ident, $builder:expr, padding, $value:expr) => { $builder.with_padding($value) };
+    (@prop $ctx:ident, $builder:expr, preserve_ratio, $value:expr) => { $builder.with_preserve_ratio($value) };
+    (@prop $ctx:ident, $builder:expr, $name:ident, $value:expr) => {
+        compile_error!(concat!("Unknown property `", stringify!($name), "` in mooeye::ui!."))
+    };
+
+    // --- entry point ---
+    ($ctx:expr, $kind:ident ($($args:tt)*) $({ $($children:tt)* })? $(,)?) => {{
+        let ctx: &$crate::ggez::Context = $ctx;
+        (|| -> $crate::ggez::GameResult<_> { Ok($crate::ui!(@element ctx; $kind($($args)*) $({ $($children)* })?)) })()
+    }};
+}
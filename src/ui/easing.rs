@@ -0,0 +1,166 @@
+use std::f32::consts::PI;
+
+/// An easing curve that controls how the progress of a [super::Transition] is mapped to the blending of layout and visuals.
+/// All curves map 0. to 0. and 1. to 1., but some (back, elastic) temporarily overshoot the target.
+#[derive(Clone, Copy, Debug, Default)]
+pub enum Easing {
+    /// Linear interpolation, the default.
+    #[default]
+    Linear,
+    /// Quadratic curve, starting slow and accelerating.
+    QuadIn,
+    /// Quadratic curve, starting fast and decelerating.
+    QuadOut,
+    /// Quadratic curve, accelerating in the first and decelerating in the second half.
+    QuadInOut,
+    /// Cubic curve, starting slow and accelerating.
+    CubicIn,
+    /// Cubic curve, starting fast and decelerating.
+    CubicOut,
+    /// Cubic curve, accelerating in the first and decelerating in the second half.
+    CubicInOut,
+    /// Moves slightly backwards before accelerating towards the target.
+    BackIn,
+    /// Overshoots the target slightly before settling.
+    BackOut,
+    /// Moves slightly backwards at the start and overshoots slightly at the end.
+    BackInOut,
+    /// Oscillates with growing amplitude before snapping to the target.
+    ElasticIn,
+    /// Snaps to the target and oscillates around it with shrinking amplitude.
+    ElasticOut,
+    /// Bounces off the start with growing height before reaching the target.
+    BounceIn,
+    /// Reaches the target and bounces off it like a dropped ball.
+    BounceOut,
+    /// Jumps to the target in the specified number of equal steps.
+    Steps(u32),
+    /// A cubic bezier curve from (0, 0) to (1, 1) with the two specified control points (x1, y1, x2, y2), as known from CSS.
+    /// The x-coordinates of the control points should be between 0 and 1.
+    CubicBezier(f32, f32, f32, f32),
+    /// A custom function. Should map 0. to 0. and 1. to 1.
+    Custom(fn(f32) -> f32),
+}
+
+impl Easing {
+    /// Applies this easing curve to the passed progress value, which is clamped to [0, 1] first.
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0., 1.);
+        match *self {
+            Easing::Linear => t,
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => 1. - (1. - t) * (1. - t),
+            Easing::QuadInOut => {
+                if t < 0.5 {
+                    2. * t * t
+                } else {
+                    1. - (-2. * t + 2.).powi(2) / 2.
+                }
+            }
+            Easing::CubicIn => t.powi(3),
+            Easing::CubicOut => 1. - (1. - t).powi(3),
+            Easing::CubicInOut => {
+                if t < 0.5 {
+                    4. * t.powi(3)
+                } else {
+                    1. - (-2. * t + 2.).powi(3) / 2.
+                }
+            }
+            Easing::BackIn => back_in(t),
+            Easing::BackOut => 1. - back_in(1. - t),
+            Easing::BackInOut => {
+                if t < 0.5 {
+                    back_in(2. * t) / 2.
+                } else {
+                    1. - back_in(2. - 2. * t) / 2.
+                }
+            }
+            Easing::ElasticIn => 1. - elastic_out(1. - t),
+            Easing::ElasticOut => elastic_out(t),
+            Easing::BounceIn => 1. - bounce_out(1. - t),
+            Easing::BounceOut => bounce_out(t),
+            Easing::Steps(steps) => {
+                let steps = steps.max(1) as f32;
+                (t * steps).floor() / steps
+            }
+            Easing::CubicBezier(x1, y1, x2, y2) => cubic_bezier(x1, y1, x2, y2, t),
+            Easing::Custom(function) => function(t),
+        }
+    }
+}
+
+/// The back-in curve, slightly moving below 0 before accelerating towards 1.
+fn back_in(t: f32) -> f32 {
+    const C1: f32 = 1.70158;
+    const C3: f32 = C1 + 1.;
+    C3 * t.powi(3) - C1 * t * t
+}
+
+/// The elastic-out curve, oscillating around 1 with exponentially shrinking amplitude.
+fn elastic_out(t: f32) -> f32 {
+    if t <= 0. || t >= 1. {
+        t
+    } else {
+        2f32.powf(-10. * t) * ((t * 10. - 0.75) * (2. * PI / 3.)).sin() + 1.
+    }
+}
+
+/// The bounce-out curve, simulating a ball dropped onto the target.
+fn bounce_out(t: f32) -> f32 {
+    const N1: f32 = 7.5625;
+    const D1: f32 = 2.75;
+    if t < 1. / D1 {
+        N1 * t * t
+    } else if t < 2. / D1 {
+        let t = t - 1.5 / D1;
+        N1 * t * t + 0.75
+    } else if t < 2.5 / D1 {
+        let t = t - 2.25 / D1;
+        N1 * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D1;
+        N1 * t * t + 0.984375
+    }
+}
+
+/// Evaluates the cubic bezier curve with the control points (0, 0), (x1, y1), (x2, y2) and (1, 1) at the point with x-coordinate x.
+fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, x: f32) -> f32 {
+    // one-dimensional bezier curve with the passed inner control points
+    let bezier = |p1: f32, p2: f32, s: f32| {
+        3. * (1. - s).powi(2) * s * p1 + 3. * (1. - s) * s * s * p2 + s.powi(3)
+    };
+    // its derivative
+    let derivative = |p1: f32, p2: f32, s: f32| {
+        3. * (1. - s).powi(2) * p1 + 6. * (1. - s) * s * (p2 - p1) + 3. * s * s * (1. - p2)
+    };
+
+    // find the curve parameter belonging to x, first using newtons method and falling back to bisection
+    let mut s = x;
+    for _ in 0..8 {
+        let error = bezier(x1, x2, s) - x;
+        if error.abs() < 1e-5 {
+            return bezier(y1, y2, s);
+        }
+        let slope = derivative(x1, x2, s);
+        if slope.abs() < 1e-6 {
+            break;
+        }
+        s -= error / slope;
+    }
+
+    let (mut low, mut high) = (0., 1.);
+    s = x;
+    for _ in 0..32 {
+        let value = bezier(x1, x2, s);
+        if (value - x).abs() < 1e-5 {
+            break;
+        }
+        if value < x {
+            low = s;
+        } else {
+            high = s;
+        }
+        s = (low + high) / 2.;
+    }
+    bezier(y1, y2, s)
+}
@@ -0,0 +1,257 @@
+use std::hash::Hash;
+
+use ggez::{
+    event::{Axis, Button},
+    glam::Vec2,
+    input::keyboard::KeyMods,
+    winit::event::VirtualKeyCode,
+};
+
+use super::{DrawCache, InputState, UiElement};
+
+/// The value a gamepad stick needs to cross (in either direction) to move the focus.
+const STICK_THRESHOLD: f32 = 0.5;
+
+/// A request to move the keyboard focus, as triggered by the user pressing a navigation key.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum FocusNavigation {
+    /// Move focus to the next focusable element in tree order (Tab).
+    Next,
+    /// Move focus to the previous focusable element in tree order (Shift + Tab).
+    Previous,
+    /// Move focus to the closest focusable element in the given (normalized) screen direction (arrow keys).
+    Direction(Vec2),
+}
+
+impl FocusNavigation {
+    /// Returns the navigation requested by the keys pressed during the last frame, if any.
+    pub(crate) fn from_input(input: &InputState) -> Option<Self> {
+        if input.is_key_just_pressed(VirtualKeyCode::Tab) {
+            if input.is_mod_active(KeyMods::SHIFT) {
+                Some(Self::Previous)
+            } else {
+                Some(Self::Next)
+            }
+        } else if input.is_key_just_pressed(VirtualKeyCode::Left) {
+            Some(Self::Direction(Vec2::new(-1., 0.)))
+        } else if input.is_key_just_pressed(VirtualKeyCode::Right) {
+            Some(Self::Direction(Vec2::new(1., 0.)))
+        } else if input.is_key_just_pressed(VirtualKeyCode::Up) {
+            Some(Self::Direction(Vec2::new(0., -1.)))
+        } else if input.is_key_just_pressed(VirtualKeyCode::Down) {
+            Some(Self::Direction(Vec2::new(0., 1.)))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the navigation requested by pressing the passed gamepad button (D-pad), if any.
+    pub(crate) fn from_gamepad_button(button: Button) -> Option<Self> {
+        match button {
+            Button::DPadLeft => Some(Self::Direction(Vec2::new(-1., 0.))),
+            Button::DPadRight => Some(Self::Direction(Vec2::new(1., 0.))),
+            Button::DPadUp => Some(Self::Direction(Vec2::new(0., -1.))),
+            Button::DPadDown => Some(Self::Direction(Vec2::new(0., 1.))),
+            _ => None,
+        }
+    }
+}
+
+impl<T: Copy + Eq + Hash> UiElement<T> {
+    /// Returns the ID of the element holding the keyboard focus within this element and its successors, if any.
+    pub fn get_focus(&self) -> Option<u32> {
+        if self.focused {
+            return Some(self.id);
+        }
+        self.content.container().and_then(|cont| {
+            cont.get_children()
+                .iter()
+                .find_map(|child| child.get_focus())
+        })
+    }
+
+    /// Moves the keyboard focus to the first focusable element with the given ID within this element and its successors.
+    /// Pass None to remove the focus from all elements.
+    /// If no focusable element with that ID exists, the focus is not changed.
+    /// Call this on your root element, e.g. to focus the first button of a menu when opening it.
+    pub fn set_focus(&mut self, id: impl Into<Option<u32>>) {
+        let id = id.into();
+        let mut candidates = Vec::new();
+        self.collect_focus_candidates(&mut candidates);
+        match id {
+            None => self.focus_index(None),
+            Some(id) => {
+                if let Some(index) = candidates.iter().position(|(cand_id, ..)| *cand_id == id) {
+                    self.focus_index(Some(index));
+                }
+            }
+        }
+    }
+
+    /// Returns wether this element can receive the keyboard focus.
+    fn focusable(&self) -> bool {
+        self.focusable && self.id != 0
+    }
+
+    /// Collects ID, draw cache, focus state and mouse hover state of all focusable elements among this element and its successors in tree order.
+    fn collect_focus_candidates(&self, res: &mut Vec<(u32, DrawCache, bool, bool)>) {
+        if self.focusable() {
+            res.push((self.id, self.draw_cache, self.focused, self.mouse_hit));
+        }
+        if let Some(cont) = self.content.container() {
+            for child in cont.get_children() {
+                child.collect_focus_candidates(res);
+            }
+        }
+    }
+
+    /// Focuses the focusable element with the passed index (in the order of [UiElement::collect_focus_candidates]) and unfocuses all others.
+    fn focus_index(&mut self, index: Option<usize>) {
+        self.focus_index_rec(index, &mut 0);
+    }
+
+    /// Recursive helper of [UiElement::focus_index], counting the focusable elements visited so far.
+    fn focus_index_rec(&mut self, index: Option<usize>, counter: &mut usize) {
+        if self.focusable() {
+            self.focused = index == Some(*counter);
+            *counter += 1;
+        } else {
+            self.focused = false;
+        }
+        if let Some(cont) = self.content.container_mut() {
+            for child in cont.get_children_mut() {
+                child.focus_index_rec(index, counter);
+            }
+        }
+    }
+
+    /// Moves the keyboard focus based on the navigation keys pressed and the mouse clicks made during the last frame.
+    /// Clicking a focusable element focuses it. Navigation keys are ignored while the keyboard is captured.
+    /// Only elements that were drawn during the last frame can receive the focus this way.
+    pub(crate) fn navigate_focus(&mut self, input: &InputState, keyboard_captured: bool) {
+        let mut candidates = Vec::new();
+        self.collect_focus_candidates(&mut candidates);
+
+        // clicks focus the innermost (last in tree order) clicked element
+        if input.button_just_pressed(ggez::event::MouseButton::Left) {
+            if let Some(index) = candidates.iter().rposition(|(.., mouse_hit)| *mouse_hit) {
+                self.focus_index(Some(index));
+                return;
+            }
+        }
+
+        if keyboard_captured {
+            return;
+        }
+        if let Some(navigation) = FocusNavigation::from_input(input) {
+            self.move_focus(navigation);
+        }
+    }
+
+    /// Moves the keyboard focus as requested by the passed navigation.
+    /// If no element is focused, any navigation focuses the first focusable element.
+    fn move_focus(&mut self, navigation: FocusNavigation) {
+        let mut candidates = Vec::new();
+        self.collect_focus_candidates(&mut candidates);
+
+        let visible = |index: &usize| matches!(candidates[*index].1, DrawCache::Valid { .. });
+        let count = candidates.len();
+        let current = candidates.iter().position(|(_, _, focused, _)| *focused);
+
+        let target = match (navigation, current) {
+            // nothing focused yet: any navigation focuses the first element
+            (_, None) => (0..count).find(visible),
+            (FocusNavigation::Next, Some(current)) => (1..=count)
+                .map(|step| (current + step) % count)
+                .find(visible),
+            (FocusNavigation::Previous, Some(current)) => (1..=count)
+                .map(|step| (current + count - step) % count)
+                .find(visible),
+            (FocusNavigation::Direction(direction), Some(current)) => {
+                match candidates[current].1.center() {
+                    None => (0..count).find(visible),
+                    Some(from) => (0..count)
+                        .filter(|index| *index != current)
+                        .filter_map(|index| {
+                            let delta = candidates[index].1.center()? - from;
+                            let along = delta.dot(direction);
+                            // only consider elements actually lying in the requested direction,
+                            // preferring those close to the line of movement
+                            (along > 0.)
+                                .then(|| (index, along + 2. * (delta - along * direction).length()))
+                        })
+                        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                        .map(|(index, _)| index),
+                }
+            }
+        };
+
+        if target.is_some() {
+            self.focus_index(target);
+        }
+    }
+
+    /// Passes a gamepad button press (as received by [ggez::event::EventHandler::gamepad_button_down_event]) to this element and its children.
+    /// The D-pad moves the focus, the south button (A on most controllers) triggers the focused element.
+    /// Any button also triggers all elements it was bound to via [UiElementBuilder::with_gamepad_button](super::UiElementBuilder::with_gamepad_button).
+    /// The east button (B on most controllers) triggers all back buttons (see [UiElementBuilder::with_back](super::UiElementBuilder::with_back)).
+    /// Triggered elements send their messages during the next call of [UiElement::update].
+    /// Returns true if the button press had any effect.
+    /// Call this on your root element from your event handler or scene.
+    pub fn gamepad_button_down_event(&mut self, button: Button) -> bool {
+        if let Some(navigation) = FocusNavigation::from_gamepad_button(button) {
+            self.move_focus(navigation);
+            return true;
+        }
+        self.press_gamepad_button(button, button == Button::South)
+    }
+
+    /// Marks all elements bound to the passed button (and the focused element, if `activate_focus` is set) as triggered by the gamepad.
+    /// The east button is bound to all back buttons.
+    /// Returns true if any element was marked.
+    fn press_gamepad_button(&mut self, button: Button, activate_focus: bool) -> bool {
+        let focus_triggered = activate_focus && self.focused;
+        let bound =
+            self.gamepad_buttons.contains(&Some(button)) || (self.back && button == Button::East);
+        let mut res = false;
+        if self.id != 0 && (focus_triggered || bound) {
+            self.gamepad_triggered = true;
+            if focus_triggered {
+                self.content.focus_triggered();
+            }
+            res = true;
+        }
+        if let Some(cont) = self.content.container_mut() {
+            for child in cont.get_children_mut() {
+                res |= child.press_gamepad_button(button, activate_focus);
+            }
+        }
+        res
+    }
+
+    /// Passes a gamepad axis movement (as received by [ggez::event::EventHandler::gamepad_axis_event]) to this element.
+    /// Pushing the left stick (or an analog D-pad) in a direction moves the focus once, the stick needs to return to the center before it moves the focus again.
+    /// Returns true if the focus was moved.
+    /// Call this on your root element from your event handler or scene.
+    pub fn gamepad_axis_event(&mut self, axis: Axis, value: f32) -> bool {
+        let (previous, direction) = match axis {
+            Axis::LeftStickX | Axis::DPadX => (
+                std::mem::replace(&mut self.stick_position.x, value),
+                Vec2::new(value.signum(), 0.),
+            ),
+            // the y-axis of gamepad sticks points upwards
+            Axis::LeftStickY | Axis::DPadY => (
+                std::mem::replace(&mut self.stick_position.y, value),
+                Vec2::new(0., -value.signum()),
+            ),
+            _ => return false,
+        };
+
+        if previous.abs() < STICK_THRESHOLD && value.abs() >= STICK_THRESHOLD {
+            self.move_focus(FocusNavigation::Direction(direction));
+            true
+        } else {
+            false
+        }
+    }
+}
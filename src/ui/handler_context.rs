@@ -0,0 +1,90 @@
+use std::{collections::VecDeque, hash::Hash};
+
+use super::{DrawCache, Layout, Transition, UiContent, UiElement, UiMessage, Visuals};
+
+/// The access a mutable message handler (see [super::UiElementBuilder::with_message_handler_mut]) has to the element it belongs to.
+/// Allows changing content, layout, visuals and tooltip of the element directly, queueing transitions and emitting new messages.
+/// Changes that may affect the layout of the element invalidate its draw cache, so it is laid out again when drawn next.
+pub struct HandlerContext<'a, T: Copy + Eq + Hash> {
+    /// The element whose message handler is being called.
+    element: &'a mut UiElement<T>,
+}
+
+impl<'a, T: Copy + Eq + Hash> HandlerContext<'a, T> {
+    /// Creates a new context for the message handler of the passed element.
+    pub(crate) fn new(element: &'a mut UiElement<T>) -> Self {
+        Self { element }
+    }
+
+    /// Returns the ID of the element.
+    pub fn id(&self) -> u32 {
+        self.element.id
+    }
+
+    /// Returns the content of the element.
+    pub fn content(&self) -> &dyn UiContent<T> {
+        self.element.content.as_ref()
+    }
+
+    /// Returns the content of the element cast to the type `C`, or None if it is of another type. See [UiElement::content_as].
+    pub fn content_as<C: 'static>(&self) -> Option<&C> {
+        self.element.content_as()
+    }
+
+    /// Returns the content of the element mutably cast to the type `C`, or None if it is of another type. The content is measured again before it is drawn next, see [UiElement::content_as_mut].
+    pub fn content_as_mut<C: 'static>(&mut self) -> Option<&mut C> {
+        self.element.content_as_mut()
+    }
+
+    /// Replaces the content of the element. The size of the element is not changed, so set the layout as well if the new content needs more space.
+    pub fn set_content(&mut self, content: impl UiContent<T> + 'static) {
+        self.element.content = Box::new(content);
+        self.element.draw_cache = DrawCache::Invalid;
+    }
+
+    /// Returns the layout of the element.
+    pub fn layout(&self) -> Layout {
+        self.element.layout
+    }
+
+    /// Returns the layout of the element mutably.
+    pub fn layout_mut(&mut self) -> &mut Layout {
+        self.element.draw_cache = DrawCache::Invalid;
+        &mut self.element.layout
+    }
+
+    /// Returns the visuals of the element mutably.
+    pub fn visuals_mut(&mut self) -> &mut Visuals {
+        &mut self.element.visuals
+    }
+
+    /// Returns the hover visuals of the element mutably. If they are None, the normal visuals are displayed while hovering.
+    pub fn hover_visuals_mut(&mut self) -> &mut Option<Visuals> {
+        &mut self.element.hover_visuals
+    }
+
+    /// Returns the tooltip of the element mutably, if it has one.
+    pub fn tooltip_mut(&mut self) -> Option<&mut UiElement<T>> {
+        self.element.tooltip.as_deref_mut()
+    }
+
+    /// Replaces the tooltip of the element. Pass None to remove it.
+    pub fn set_tooltip(&mut self, tooltip: impl Into<Option<UiElement<T>>>) {
+        self.element.tooltip = tooltip.into().map(Box::new);
+    }
+
+    /// Adds a transition to the end of the transition queue of the element, see [UiElement::add_transition].
+    pub fn add_transition(&mut self, transition: Transition<T>) {
+        self.element.add_transition(transition);
+    }
+
+    /// Returns the transition queue of the element, e.g. to clear it before adding a new transition.
+    pub fn transitions_mut(&mut self) -> &mut VecDeque<Transition<T>> {
+        &mut self.element.transitions
+    }
+
+    /// Sends a message from this element. It is distributed to all elements within the same [UiElement::update] (unless it was already distributed during it) and returned to the caller.
+    pub fn emit(&mut self, message: UiMessage<T>) {
+        self.element.emitted_messages.push(message);
+    }
+}
@@ -0,0 +1,280 @@
+use std::{collections::HashSet, hash::Hash, time::Duration};
+
+use ggez::{
+    event::MouseButton,
+    glam::{Affine2, Vec2},
+    graphics::Rect,
+    input::keyboard::{KeyInput, KeyMods},
+    winit::event::VirtualKeyCode,
+};
+
+use super::{DrawCache, InputState, LayoutTree, Measure, MonospaceMeasure, UiElement, UiMessage};
+
+impl<T: Copy + Eq + Hash> UiElement<T> {
+    /// Does everything drawing this element to the passed target would do except the actual drawing:
+    /// Progresses the transitions and animations of this element and its successors by the passed time and updates their draw caches, so mouse input can find them.
+    /// `transform` needs to contain the transformation from the coordinates of the target to screen coordinates.
+    /// Changed contents and new contents of cross-fading transitions are measured by `measure`.
+    fn simulate_draw(
+        &mut self,
+        measure: &dyn Measure,
+        delta: Duration,
+        target: Rect,
+        transform: Affine2,
+    ) {
+        self.measure_changes(measure);
+        self.progress_transitions(delta);
+        self.progress_animation(delta);
+        self.update_draw_cache(target);
+
+        let own_transform = self.get_current_transform();
+        let animation_offset = self
+            .animation
+            .as_ref()
+            .map(|animation| animation.offset())
+            .unwrap_or_default();
+
+        let (inner, child_transform) = match &mut self.draw_cache {
+            DrawCache::Invalid => return,
+            DrawCache::Valid {
+                outer,
+                inner,
+                clip,
+                transform: cache_transform,
+                z,
+                ..
+            } => {
+                let (mut outer, mut inner) = (*outer, *inner);
+                outer.translate(animation_offset);
+                inner.translate(animation_offset);
+                *clip = None;
+                *cache_transform = transform
+                    * own_transform.to_affine(outer)
+                    * Affine2::from_translation(animation_offset);
+                *z = 0;
+                (inner, transform * own_transform.to_affine(outer))
+            }
+        };
+
+        if let Some(cont) = self.content.container_mut() {
+            let targets = cont.get_child_targets(inner);
+            for (child, target) in cont.get_children_mut().iter_mut().zip(targets) {
+                child.simulate_draw(measure, delta, target, child_transform);
+            }
+        }
+    }
+}
+
+/// Runs a [UiElement] frame by frame without a window, feeding it simulated mouse, keyboard and timer input.
+/// Use this to test the messages your UI sends and how its message handlers and transitions react to them.
+/// Drawing is simulated by laying out the elements in the target area. Contents receive input via [super::UiContent::update_input] and the event hooks as usual.
+/// Text is never rendered, so a [super::basic::TextInput] clicked in the harness places its caret at the end of the clicked line.
+/// Clipping, z-levels and tooltips are ignored.
+pub struct UiHarness<T: Copy + Eq + Hash> {
+    /// The element being tested.
+    root: UiElement<T>,
+    /// The screen area the element is laid out in.
+    target: Rect,
+    /// The time each simulated frame takes.
+    frame_time: Duration,
+    /// The input state passed to the next frame.
+    input: InputState,
+    /// The messages passed to the next frame in addition to the internal messages.
+    extern_messages: HashSet<UiMessage<T>>,
+    /// The measure used for contents that are measured while drawing.
+    measure: Box<dyn Measure>,
+}
+
+impl<T: Copy + Eq + Hash> UiHarness<T> {
+    /// Creates a new harness running the passed element within the passed screen area.
+    /// The element is laid out immediately, so mouse input reaches it from the first frame on.
+    /// Elements built without a [ggez::Context] should be sized by [UiElement::measure] first.
+    pub fn new(root: UiElement<T>, target: Rect) -> Self {
+        let mut harness = Self {
+            root,
+            target,
+            frame_time: Duration::from_secs_f32(1. / 60.),
+            input: InputState::default(),
+            extern_messages: HashSet::new(),
+            measure: Box::<MonospaceMeasure>::default(),
+        };
+        harness.root.simulate_draw(
+            harness.measure.as_ref(),
+            Duration::ZERO,
+            target,
+            Affine2::IDENTITY,
+        );
+        harness
+    }
+
+    /// Sets the measure used for contents that are measured while drawing, such as changed contents and the new contents of cross-fading transitions.
+    /// Defaults to a [MonospaceMeasure].
+    pub fn with_measure(mut self, measure: impl Measure + 'static) -> Self {
+        self.measure = Box::new(measure);
+        self
+    }
+
+    /// Sets the time each simulated frame takes. Defaults to 1/60 of a second.
+    pub fn with_frame_time(mut self, frame_time: Duration) -> Self {
+        self.frame_time = frame_time;
+        self
+    }
+
+    /// Returns the element being tested.
+    pub fn root(&self) -> &UiElement<T> {
+        &self.root
+    }
+
+    /// Returns the element being tested mutably, e.g. to add transitions.
+    pub fn root_mut(&mut self) -> &mut UiElement<T> {
+        &mut self.root
+    }
+
+    /// Returns the element being tested, consuming the harness.
+    pub fn into_root(self) -> UiElement<T> {
+        self.root
+    }
+
+    /// Returns the input state that will be passed to the next frame.
+    pub fn input(&self) -> &InputState {
+        &self.input
+    }
+
+    /// Returns the current layout of the element being tested within the target area, respecting running layout transitions.
+    pub fn layout(&self) -> LayoutTree {
+        self.root.compute_layout(self.target)
+    }
+
+    /// Moves the mouse cursor to the passed position in screen coordinates.
+    pub fn move_mouse(&mut self, position: impl Into<Vec2>) {
+        let position = position.into();
+        self.input.mouse_delta += position - self.input.mouse_position;
+        self.input.mouse_position = position;
+    }
+
+    /// Moves the mouse cursor to the center of the first element with the passed ID.
+    /// Panics if there is no such element or it does not fit into its target.
+    pub fn move_mouse_to(&mut self, id: u32) {
+        let center = self
+            .layout()
+            .get(id)
+            .filter(|layout| layout.fits)
+            .map(|layout| layout.outer.center())
+            .unwrap_or_else(|| panic!("No visible element with ID {} to move the mouse to.", id));
+        self.move_mouse(center);
+    }
+
+    /// Presses the passed mouse button down. It is held until [UiHarness::release_button] is called.
+    pub fn press_button(&mut self, button: MouseButton) {
+        if self.input.buttons_pressed.insert(button) {
+            self.input.buttons_just_pressed.insert(button);
+        }
+    }
+
+    /// Releases the passed mouse button.
+    pub fn release_button(&mut self, button: MouseButton) {
+        self.input.buttons_pressed.remove(&button);
+    }
+
+    /// Presses the passed key down. It is held until [UiHarness::release_key] is called.
+    /// The key press is also passed to the element immediately, see [UiElement::key_down_event].
+    /// Returns true if the key press was consumed by any element.
+    pub fn press_key(&mut self, key: VirtualKeyCode) -> bool {
+        if self.input.keys_pressed.insert(key) {
+            self.input.keys_just_pressed.insert(key);
+        }
+        self.root.key_down_event(KeyInput {
+            scancode: 0,
+            keycode: Some(key),
+            mods: self.input.mods,
+        })
+    }
+
+    /// Releases the passed key.
+    pub fn release_key(&mut self, key: VirtualKeyCode) {
+        self.input.keys_pressed.remove(&key);
+    }
+
+    /// Sets the active modifier keys.
+    pub fn set_mods(&mut self, mods: KeyMods) {
+        self.input.mods = mods;
+    }
+
+    /// Moves the mouse wheel by the passed amount of lines, see [UiElement::mouse_wheel_event].
+    /// The movement is passed to the element immediately, the resulting [UiMessage::Scrolled] is sent during the next frame.
+    /// Returns true if the movement was consumed by any element.
+    pub fn scroll(&mut self, x: f32, y: f32) -> bool {
+        self.root.mouse_wheel_event_with_input(&self.input, x, y)
+    }
+
+    /// Types the passed text, passing every character to the element as a text input event (see [UiElement::text_input_event]).
+    /// Returns true if all characters were consumed.
+    pub fn type_text(&mut self, text: &str) -> bool {
+        let mut consumed = true;
+        for character in text.chars() {
+            consumed &= self.root.text_input_event(character);
+        }
+        consumed
+    }
+
+    /// Passes the passed message to the element as an external message during the next frame.
+    pub fn send(&mut self, message: UiMessage<T>) {
+        self.extern_messages.insert(message);
+    }
+
+    /// Simulates a single frame: Updates the element with the current input and external messages, then progresses time and lays it out as if it was drawn.
+    /// Returns the internal messages sent during the frame.
+    pub fn frame(&mut self) -> HashSet<UiMessage<T>> {
+        self.input.delta = self.frame_time;
+        self.input.time_since_start += self.frame_time;
+
+        let messages = self
+            .root
+            .update_with_input(&self.input, std::mem::take(&mut self.extern_messages));
+        self.root.simulate_draw(
+            self.measure.as_ref(),
+            self.frame_time,
+            self.target,
+            Affine2::IDENTITY,
+        );
+
+        // pressing and moving only counts for a single frame
+        self.input.buttons_just_pressed.clear();
+        self.input.keys_just_pressed.clear();
+        self.input.mouse_delta = Vec2::ZERO;
+
+        messages
+    }
+
+    /// Simulates as many frames as needed for the passed time to pass and returns all internal messages sent during them.
+    pub fn advance(&mut self, time: Duration) -> HashSet<UiMessage<T>> {
+        let mut messages = HashSet::new();
+        let mut passed = Duration::ZERO;
+        while passed < time {
+            messages.extend(self.frame());
+            passed += self.frame_time;
+        }
+        messages
+    }
+
+    /// Moves the mouse to the center of the first element with the passed ID and clicks it with the left mouse button.
+    /// Simulates two frames (pressing and releasing) and returns all internal messages sent during them.
+    pub fn click(&mut self, id: u32) -> HashSet<UiMessage<T>> {
+        self.move_mouse_to(id);
+        self.press_button(MouseButton::Left);
+        let mut messages = self.frame();
+        self.release_button(MouseButton::Left);
+        messages.extend(self.frame());
+        messages
+    }
+
+    /// Presses and releases the passed key.
+    /// Simulates two frames (pressing and releasing) and returns all internal messages sent during them.
+    pub fn tap_key(&mut self, key: VirtualKeyCode) -> HashSet<UiMessage<T>> {
+        self.press_key(key);
+        let mut messages = self.frame();
+        self.release_key(key);
+        messages.extend(self.frame());
+        messages
+    }
+}
@@ -0,0 +1,95 @@
+use std::hash::Hash;
+
+use ggez::mint::Point2;
+
+use super::{DrawCache, UiElement};
+
+/// The path index used to refer to the tooltip of an element instead of one of its children.
+const TOOLTIP_INDEX: usize = usize::MAX;
+
+impl<T: Copy + Eq + Hash> UiElement<T> {
+    /// Determines the topmost element below the mouse cursor (based on the draw caches of the last frame) and marks it and all its ancestors as hovered.
+    /// Elements are considered in the order they were drawn in, with higher z-levels taking precedence.
+    /// Of the marked elements, the innermost one that can send messages becomes the target of mouse input.
+    /// Elements that pass through the mouse and elements currently being dragged are ignored.
+    pub(crate) fn update_mouse_hit(&mut self, mouse: Point2<f32>) {
+        let mut topmost = None;
+        self.find_topmost(mouse, &mut Vec::new(), &mut topmost);
+        self.mark_hit(topmost.as_ref().map(|(_, path)| path.as_slice()));
+    }
+
+    /// Recursively searches this element and its successors (and visible tooltips) for the topmost element containing the mouse position.
+    /// `path` contains the child indices leading from the root to this element, `topmost` the z-level and path of the topmost element found so far.
+    fn find_topmost(
+        &self,
+        mouse: Point2<f32>,
+        path: &mut Vec<usize>,
+        topmost: &mut Option<(i32, Vec<usize>)>,
+    ) {
+        // dragged elements follow the mouse and would always be on top
+        if self.is_dragged() {
+            return;
+        }
+
+        let contains = self.draw_cache.contains(mouse);
+
+        if contains && !self.pass_through {
+            if let DrawCache::Valid { z, .. } = self.draw_cache {
+                // elements drawn later are on top of elements drawn earlier on the same z-level
+                if topmost.as_ref().is_none_or(|(top_z, _)| z >= *top_z) {
+                    *topmost = Some((z, path.clone()));
+                }
+            }
+        }
+
+        if let Some(cont) = self.content.container() {
+            let children = cont.get_children();
+            let mut visit = |index: usize| {
+                path.push(index);
+                children[index].find_topmost(mouse, path, topmost);
+                path.pop();
+            };
+            if cont.reverse_draw_order() {
+                (0..children.len()).rev().for_each(&mut visit);
+            } else {
+                (0..children.len()).for_each(&mut visit);
+            }
+        }
+
+        // tooltips are drawn last and only while their element is hovered
+        if contains {
+            if let Some(tooltip) = &self.tooltip {
+                path.push(TOOLTIP_INDEX);
+                tooltip.find_topmost(mouse, path, topmost);
+                path.pop();
+            }
+        }
+    }
+
+    /// Marks all elements along the passed path as hovered and all others as not hovered.
+    /// Also determines the mouse target as the innermost element along the path with an ID other than 0.
+    /// Returns true if the mouse target is this element or one of its successors.
+    fn mark_hit(&mut self, path: Option<&[usize]>) -> bool {
+        self.mouse_hit = path.is_some();
+
+        // returns the remaining path if it leads through the child with the passed index
+        let sub_path = |index: usize| {
+            path.and_then(|path| path.split_first())
+                .filter(|(first, _)| **first == index)
+                .map(|(_, rest)| rest)
+        };
+
+        let mut target_below = false;
+        if let Some(cont) = self.content.container_mut() {
+            for (index, child) in cont.get_children_mut().iter_mut().enumerate() {
+                target_below |= child.mark_hit(sub_path(index));
+            }
+        }
+        if let Some(tooltip) = &mut self.tooltip {
+            target_below |= tooltip.mark_hit(sub_path(TOOLTIP_INDEX));
+        }
+
+        self.mouse_target = self.mouse_hit && !target_below && self.id != 0 && !self.pass_through;
+        target_below || self.mouse_target
+    }
+}
@@ -0,0 +1,95 @@
+use std::{collections::HashSet, time::Duration};
+
+use ggez::{
+    event::MouseButton, glam::Vec2, input::keyboard::KeyMods, winit::event::VirtualKeyCode, Context,
+};
+
+/// The mouse buttons whose state is copied from the [Context] by [InputState::from_context].
+const MOUSE_BUTTONS: [MouseButton; 3] =
+    [MouseButton::Left, MouseButton::Right, MouseButton::Middle];
+
+/// A snapshot of the mouse, keyboard and timer state of a single frame, as read by [super::UiElement::update_with_input].
+/// Usually created from the [Context] via [InputState::from_context], but can also be filled manually to simulate input without a window, see [super::UiHarness].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InputState {
+    /// The position of the mouse cursor in screen coordinates.
+    pub mouse_position: Vec2,
+    /// The distance the mouse cursor moved during the last frame.
+    pub mouse_delta: Vec2,
+    /// The mouse buttons currently held down.
+    pub buttons_pressed: HashSet<MouseButton>,
+    /// The mouse buttons pressed down during the last frame.
+    pub buttons_just_pressed: HashSet<MouseButton>,
+    /// The keys currently held down.
+    pub keys_pressed: HashSet<VirtualKeyCode>,
+    /// The keys pressed down during the last frame.
+    pub keys_just_pressed: HashSet<VirtualKeyCode>,
+    /// The currently active modifier keys.
+    pub mods: KeyMods,
+    /// The duration of the last frame.
+    pub delta: Duration,
+    /// The time since the start of the game.
+    pub time_since_start: Duration,
+}
+
+impl InputState {
+    /// Creates a snapshot of the current input state of the passed [Context].
+    /// Only the left, right and middle mouse buttons are recorded.
+    pub fn from_context(ctx: &Context) -> Self {
+        let mouse = &ctx.mouse;
+        let keyboard = &ctx.keyboard;
+        Self {
+            mouse_position: mouse.position().into(),
+            mouse_delta: mouse.delta().into(),
+            buttons_pressed: MOUSE_BUTTONS
+                .into_iter()
+                .filter(|button| mouse.button_pressed(*button))
+                .collect(),
+            buttons_just_pressed: MOUSE_BUTTONS
+                .into_iter()
+                .filter(|button| mouse.button_just_pressed(*button))
+                .collect(),
+            keys_pressed: keyboard.pressed_keys().clone(),
+            keys_just_pressed: keyboard
+                .pressed_keys()
+                .iter()
+                .filter(|key| keyboard.is_key_just_pressed(**key))
+                .copied()
+                .collect(),
+            mods: keyboard.active_mods(),
+            delta: ctx.time.delta(),
+            time_since_start: ctx.time.time_since_start(),
+        }
+    }
+
+    /// Returns wether the passed mouse button is currently held down.
+    pub fn button_pressed(&self, button: MouseButton) -> bool {
+        self.buttons_pressed.contains(&button)
+    }
+
+    /// Returns wether the passed mouse button was pressed down during the last frame.
+    pub fn button_just_pressed(&self, button: MouseButton) -> bool {
+        self.buttons_just_pressed.contains(&button)
+    }
+
+    /// Returns wether the passed key is currently held down.
+    pub fn is_key_pressed(&self, key: VirtualKeyCode) -> bool {
+        self.keys_pressed.contains(&key)
+    }
+
+    /// Returns wether the passed key was pressed down during the last frame.
+    pub fn is_key_just_pressed(&self, key: VirtualKeyCode) -> bool {
+        self.keys_just_pressed.contains(&key)
+    }
+
+    /// Returns wether all of the passed modifier keys are currently active.
+    pub fn is_mod_active(&self, mods: KeyMods) -> bool {
+        self.mods.contains(mods)
+    }
+}
+
+impl From<&Context> for InputState {
+    fn from(ctx: &Context) -> Self {
+        Self::from_context(ctx)
+    }
+}
@@ -86,6 +86,34 @@ impl Size {
         }
     }
 
+    /// Returns a size between this and the passed size. Self is weighted by (1-ratio), while other is weighted by ratio.
+    /// The boundaries are blended, the variant switches from the variant of self to the variant of other at half the way.
+    pub(crate) fn average(&self, other: Size, ratio: f32) -> Self {
+        if ratio <= 0. {
+            return *self;
+        }
+        if ratio >= 1. {
+            return other;
+        }
+        // equal (possibly infinite) boundaries are kept as they are
+        let blend = |a: f32, b: f32| {
+            if a == b {
+                a
+            } else {
+                a * (1. - ratio) + b * ratio
+            }
+        };
+        let (min, max) = (
+            blend(self.min(), other.min()),
+            blend(self.max(), other.max()),
+        );
+        match if ratio < 0.5 { self } else { &other } {
+            Size::Fixed(_) => Self::Fixed(min),
+            Size::Fill(..) => Self::Fill(min, max),
+            Size::Shrink(..) => Self::Shrink(min, max),
+        }
+    }
+
     /// Returns a new [Size] with the same boundaries, but variant changed to [Size::Fixed].
     pub fn to_fixed(self) -> Self {
         match self {
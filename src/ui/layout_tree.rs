@@ -0,0 +1,149 @@
+use std::hash::Hash;
+
+use ggez::{
+    glam::Vec2,
+    graphics::{self, Drawable, GraphicsContext, Rect},
+};
+
+use super::UiElement;
+
+/// Measures the size of contents whose size depends on fonts or image files, such as text and images.
+/// Normally, contents measure themselves using the [ggez::Context] when being converted to elements.
+/// When laying out UIs without a context (e.g. in tests running on machines without a GPU or window), a measure can supply these sizes instead, see [UiElement::measure].
+pub trait Measure {
+    /// Returns the size (in pixels) the passed text takes up when drawn.
+    fn text_size(&self, text: &graphics::Text) -> Vec2;
+
+    /// Returns the size (in pixels) of the passed image.
+    /// Default implementation returns the pixel dimensions of the image.
+    fn image_size(&self, image: &graphics::Image) -> Vec2 {
+        Vec2::new(image.width() as f32, image.height() as f32)
+    }
+}
+
+impl Measure for GraphicsContext {
+    fn text_size(&self, text: &graphics::Text) -> Vec2 {
+        text.dimensions(self)
+            .map(|rect| Vec2::new(rect.w, rect.h))
+            .unwrap_or_default()
+    }
+}
+
+/// A simple [Measure] that pretends every text uses a monospaced font, making text sizes independent of any font files.
+/// Useful to compute deterministic layouts in tests. Text bounds and wrapping are ignored.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MonospaceMeasure {
+    /// The width (in pixels) of every character.
+    pub glyph_width: f32,
+    /// The height (in pixels) of every line.
+    pub line_height: f32,
+}
+
+impl MonospaceMeasure {
+    /// Creates a new measure with the specified character width and line height.
+    pub fn new(glyph_width: f32, line_height: f32) -> Self {
+        Self {
+            glyph_width,
+            line_height,
+        }
+    }
+}
+
+impl Default for MonospaceMeasure {
+    fn default() -> Self {
+        Self::new(8., 16.)
+    }
+}
+
+impl Measure for MonospaceMeasure {
+    fn text_size(&self, text: &graphics::Text) -> Vec2 {
+        let contents = text.contents();
+        let lines = contents.split('\n');
+        Vec2::new(
+            lines
+                .clone()
+                .map(|line| line.chars().count())
+                .max()
+                .unwrap_or_default() as f32
+                * self.glyph_width,
+            lines.count() as f32 * self.line_height,
+        )
+    }
+}
+
+/// The resolved layout of a [UiElement] and all its children, as computed by [UiElement::compute_layout].
+/// Contains the same rectangles the elements would be drawn to, without drawing anything.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LayoutTree {
+    /// The ID of the element.
+    pub id: u32,
+    /// The short name of the type of the content of the element, see [super::UiContent::content_name].
+    pub content: &'static str,
+    /// The rectangle the element occupies, including its padding.
+    pub outer: Rect,
+    /// The rectangle the content of the element is drawn to.
+    pub inner: Rect,
+    /// Wether the element fits into the space provided to it. Elements that do not fit are not drawn, and neither are their children.
+    pub fits: bool,
+    /// The layouts of the children of the element, in the order of [super::UiContainer::get_children]. Empty for leaf elements and elements that do not fit.
+    pub children: Vec<LayoutTree>,
+}
+
+impl LayoutTree {
+    /// Returns the layout of the first element (in depth-first order, starting with this one) with the specified ID, if there is any.
+    pub fn get(&self, id: u32) -> Option<&LayoutTree> {
+        if self.id == id {
+            return Some(self);
+        }
+        self.children.iter().find_map(|child| child.get(id))
+    }
+}
+
+impl<T: Copy + Eq + Hash> UiElement<T> {
+    /// Computes the rectangles this element and all its children would be drawn to if this element was drawn to the passed target, without drawing anything.
+    /// Requires neither a [ggez::Context] nor a [graphics::Canvas], so layouts can be tested on machines without a GPU or window.
+    /// Layout transitions currently going on are respected, transforms and animation offsets (which do not affect the layout) are not.
+    pub fn compute_layout(&self, target: Rect) -> LayoutTree {
+        let (outer, inner) = self.get_current_bounds(target);
+        // the same bounds check as when drawing
+        let fits = outer.w <= target.w + 0.01 && outer.h <= target.h + 0.01;
+
+        let children = match self.content.container() {
+            Some(cont) if fits => cont
+                .get_children()
+                .iter()
+                .zip(cont.get_child_targets(inner))
+                .map(|(child, target)| child.compute_layout(target))
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        LayoutTree {
+            id: self.id,
+            content: self.content.content_name(),
+            outer,
+            inner,
+            fits,
+            children,
+        }
+    }
+
+    /// Resizes this element and all its successors (including tooltips) to fit their contents as measured by the passed [Measure], just as when converting the contents to elements.
+    /// Use this to lay out elements built without a [ggez::Context] (e.g. via [super::UiElementBuilder::new]) with [UiElement::compute_layout].
+    /// Sizes set manually on measured elements are overwritten. New contents of cross-fading transitions are measured as well.
+    pub fn measure(&mut self, measure: &dyn Measure) {
+        self.content.measure(measure, &mut self.layout);
+        self.measure_pending = false;
+        self.measure_changes(measure);
+
+        if let Some(cont) = self.content.container_mut() {
+            for child in cont.get_children_mut() {
+                child.measure(measure);
+            }
+        }
+
+        if let Some(tooltip) = &mut self.tooltip {
+            tooltip.measure(measure);
+        }
+    }
+}
@@ -0,0 +1,942 @@
+use std::{
+    hash::Hash,
+    io::Read,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use ggez::{
+    glam::Vec2,
+    graphics::{self, Color, TextFragment},
+    winit::event::VirtualKeyCode,
+    Context, GameError,
+};
+use serde::Deserialize;
+
+use crate::sprite::SpritePool;
+
+use super::{containers, Alignment, Layout, Size, UiContainer, UiContent, UiElement, Visuals};
+
+/// An error that occured while loading a UI description.
+#[derive(Debug)]
+pub enum LoadError {
+    /// The description file could not be read.
+    Io(GameError),
+    /// The description is not valid JSON or does not have the expected structure.
+    Parse {
+        /// The line (starting at 1) the error was found in.
+        line: usize,
+        /// The column (starting at 1) the error was found in.
+        column: usize,
+        /// A description of the error, including its position.
+        message: String,
+    },
+    /// The description is well-formed, but describes an element that cannot be created, e.g. because a referenced image does not exist.
+    Invalid {
+        /// The path from the root element to the invalid element, e.g. `root.children[2].tooltip`.
+        element: String,
+        /// A description of the error.
+        message: String,
+    },
+}
+
+impl std::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadError::Io(error) => write!(f, "Could not read UI description: {}", error),
+            // the message of serde_json already contains the position
+            LoadError::Parse { message, .. } => write!(f, "Invalid UI description: {}", message),
+            LoadError::Invalid { element, message } => {
+                write!(
+                    f,
+                    "Invalid element {} in UI description: {}",
+                    element, message
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+impl From<LoadError> for GameError {
+    fn from(value: LoadError) -> Self {
+        GameError::CustomError(value.to_string())
+    }
+}
+
+/// Loads UI element trees from declarative JSON descriptions, so UIs can be changed without recompiling.
+/// ### Format
+/// Every element is an object containing its `content` and optionally its `id`, `layout`, `visuals`, `hover_visuals`, `focus_visuals`, `tooltip` (another element),
+/// `keys` (names of [VirtualKeyCode]s triggering the element), `classes` (see [super::Theme]) and `focusable`.
+/// The content is one of
+/// * `"Empty"`,
+/// * `{"Text": {"text": "Hello", "font": "Bahnschrift", "scale": 32, "color": [255, 255, 255], "wrap_width": 200}}` (all but `text` optional, the font needs to be added to the context first),
+/// * `{"Image": {"path": "/moo.png", "scale": 4}}`,
+/// * `{"Sprite": {"path": "/moo-sheet", "frame_time": 0.25, "scale": 4}}` (requires a [SpritePool], see [UiLoader::with_sprite_pool]),
+/// * `{"VerticalBox": {"spacing": 5, "children": [...]}}` and `{"HorizontalBox": ...}`,
+/// * `{"StackBox": {"children": [...]}}` (first child on top),
+/// * `{"GridBox": {"columns": 2, "rows": 3, "spacing": [5, 5], "children": [{"x": 0, "y": 1, "element": {...}}]}}`.
+///
+/// Layouts may contain `x_alignment` and `y_alignment` (`"Min"`, `"Center"`, `"Max"`), `x_offset`, `y_offset`, `x_size` and `y_size`
+/// (`{"Fixed": 40}`, `{"Fill": [0, null]}`, `{"Shrink": [20, 100]}`, where null means infinity), `padding` and `preserve_ratio`.
+/// Values not set keep the defaults of the content, just as when using [UiContent::to_element_builder].
+/// Visuals contain `background`, `border` (colors as `[r, g, b]` or `[r, g, b, a]`), `border_width` and `corner_radius` (a number or one per side).
+#[derive(Default)]
+pub struct UiLoader<'a> {
+    /// The sprite pool sprites are initialized from, if any.
+    sprite_pool: Option<&'a SpritePool>,
+}
+
+impl<'a> UiLoader<'a> {
+    /// Creates a new loader without a sprite pool.
+    pub fn new() -> Self {
+        Self { sprite_pool: None }
+    }
+
+    /// Sets the sprite pool sprites in loaded descriptions are initialized from.
+    pub fn with_sprite_pool(mut self, sprite_pool: &'a SpritePool) -> Self {
+        self.sprite_pool = Some(sprite_pool);
+        self
+    }
+
+    /// Loads the UI description at the specified path (relative to the ggez resource directory) and creates the described element tree.
+    pub fn load<T: Copy + Eq + Hash + 'static>(
+        &self,
+        ctx: &Context,
+        path: impl AsRef<Path>,
+    ) -> Result<UiElement<T>, LoadError> {
+        self.parse(ctx, &read_source(ctx, path.as_ref())?)
+    }
+
+    /// Creates the element tree described by the passed JSON string.
+    /// The description is checked as by [UiLoader::check] before any element is created.
+    pub fn parse<T: Copy + Eq + Hash + 'static>(
+        &self,
+        ctx: &Context,
+        source: &str,
+    ) -> Result<UiElement<T>, LoadError> {
+        let description = describe(source)?;
+        self.validate(&description, "root")?;
+        self.build(ctx, description, "root")
+    }
+
+    /// Checks the passed JSON string for errors without creating any elements: Its syntax and structure, the positions of grid children, the names of keys and the availability of sprites.
+    /// Does not need a [Context], so descriptions can also be checked in tests or tools. Images and fonts are only checked when the elements are created.
+    pub fn check(&self, source: &str) -> Result<(), LoadError> {
+        self.validate(&describe(source)?, "root")
+    }
+
+    /// Recursively checks the passed description for errors that do not require a [Context]. `path` describes the position of the element in the tree, for error messages.
+    fn validate(&self, description: &ElementDescription, path: &str) -> Result<(), LoadError> {
+        let invalid = |message: String| LoadError::Invalid {
+            element: path.to_owned(),
+            message,
+        };
+
+        let children = match &description.content {
+            ContentDescription::Empty
+            | ContentDescription::Text { .. }
+            | ContentDescription::Image { .. } => Vec::new(),
+            ContentDescription::Sprite {
+                path: sprite,
+                frame_time,
+                ..
+            } => {
+                let pool = self.sprite_pool.ok_or_else(|| {
+                    invalid(
+                        "Sprites can only be loaded if the loader has a sprite pool.".to_owned(),
+                    )
+                })?;
+                pool.init_sprite(sprite, Duration::from_secs_f32(frame_time.unwrap_or(0.25)))
+                    .map_err(|_| invalid(format!("Sprite pool does not contain {}.", sprite)))?;
+                Vec::new()
+            }
+            ContentDescription::VerticalBox { children, .. }
+            | ContentDescription::HorizontalBox { children, .. }
+            | ContentDescription::StackBox { children } => children.iter().collect(),
+            ContentDescription::GridBox {
+                columns,
+                rows,
+                children,
+                ..
+            } => {
+                for (index, child) in children.iter().enumerate() {
+                    if child.x >= *columns || child.y >= *rows {
+                        return Err(LoadError::Invalid {
+                            element: format!("{}.children[{}]", path, index),
+                            message: format!(
+                                "Position ({}, {}) is outside of the {}x{} grid.",
+                                child.x, child.y, columns, rows
+                            ),
+                        });
+                    }
+                }
+                children.iter().map(|child| &child.element).collect()
+            }
+        };
+
+        for (index, child) in children.into_iter().enumerate() {
+            self.validate(child, &format!("{}.children[{}]", path, index))?;
+        }
+        for key in &description.keys {
+            key_from_name(key).ok_or_else(|| invalid(format!("Unknown key {}.", key)))?;
+        }
+        if let Some(tooltip) = &description.tooltip {
+            self.validate(tooltip, &format!("{}.tooltip", path))?;
+        }
+
+        Ok(())
+    }
+
+    /// Recursively creates the element described by the passed description. `path` describes the position of the element in the tree, for error messages.
+    fn build<T: Copy + Eq + Hash + 'static>(
+        &self,
+        ctx: &Context,
+        description: ElementDescription,
+        path: &str,
+    ) -> Result<UiElement<T>, LoadError> {
+        let invalid = |message: String| LoadError::Invalid {
+            element: path.to_owned(),
+            message,
+        };
+        let id = description.id;
+
+        let mut element = match description.content {
+            ContentDescription::Empty => ().to_element(id, ctx),
+            ContentDescription::Text {
+                text,
+                font,
+                scale,
+                color,
+                wrap_width,
+            } => {
+                let mut fragment = TextFragment::new(text);
+                if let Some(color) = color {
+                    fragment = fragment.color(color.to_color());
+                }
+                let mut text = graphics::Text::new(fragment);
+                if let Some(scale) = scale {
+                    text.set_scale(scale);
+                }
+                if let Some(wrap_width) = wrap_width {
+                    text.set_wrap(true)
+                        .set_bounds(Vec2::new(wrap_width, f32::INFINITY));
+                }
+                if let Some(font) = font {
+                    // measuring fails if the font was not added to the context
+                    text.set_font(&font);
+                    text.measure(&ctx.gfx)
+                        .map_err(|_| invalid(format!("Unknown font {}.", font)))?;
+                }
+                text.to_element(id, ctx)
+            }
+            ContentDescription::Image { path: image, scale } => {
+                let image = graphics::Image::from_path(ctx, &image).map_err(|error| {
+                    invalid(format!("Could not load image {}: {}", image, error))
+                })?;
+                let scale = scale.unwrap_or(1.);
+                image
+                    .to_element_builder(id, ctx)
+                    .scaled(scale, scale)
+                    .build()
+            }
+            ContentDescription::Sprite {
+                path: sprite,
+                frame_time,
+                scale,
+            } => {
+                let pool = self.sprite_pool.ok_or_else(|| {
+                    invalid(
+                        "Sprites can only be loaded if the loader has a sprite pool.".to_owned(),
+                    )
+                })?;
+                let sprite = pool
+                    .init_sprite(&sprite, Duration::from_secs_f32(frame_time.unwrap_or(0.25)))
+                    .map_err(|_| invalid(format!("Sprite pool does not contain {}.", sprite)))?;
+                let scale = scale.unwrap_or(1.);
+                sprite
+                    .to_element_builder(id, ctx)
+                    .scaled(scale, scale)
+                    .build()
+            }
+            ContentDescription::VerticalBox { spacing, children } => {
+                let mut vbox = match spacing {
+                    Some(spacing) => containers::VerticalBox::new_spaced(spacing),
+                    None => containers::VerticalBox::new(),
+                };
+                for (index, child) in children.into_iter().enumerate() {
+                    vbox.add(self.build(ctx, child, &format!("{}.children[{}]", path, index))?);
+                }
+                vbox.to_element(id, ctx)
+            }
+            ContentDescription::HorizontalBox { spacing, children } => {
+                let mut hbox = match spacing {
+                    Some(spacing) => containers::HorizontalBox::new_spaced(spacing),
+                    None => containers::HorizontalBox::new(),
+                };
+                for (index, child) in children.into_iter().enumerate() {
+                    hbox.add(self.build(ctx, child, &format!("{}.children[{}]", path, index))?);
+                }
+                hbox.to_element(id, ctx)
+            }
+            ContentDescription::StackBox { children } => {
+                let mut stack = containers::StackBox::new();
+                for (index, child) in children.into_iter().enumerate() {
+                    stack.add(self.build(ctx, child, &format!("{}.children[{}]", path, index))?);
+                }
+                stack.to_element(id, ctx)
+            }
+            ContentDescription::GridBox {
+                columns,
+                rows,
+                spacing,
+                children,
+            } => {
+                let mut grid = match spacing {
+                    Some((horizontal, vertical)) => {
+                        containers::GridBox::new_spaced(columns, rows, horizontal, vertical)
+                    }
+                    None => containers::GridBox::new(columns, rows),
+                };
+                for (index, child) in children.into_iter().enumerate() {
+                    let child_path = format!("{}.children[{}]", path, index);
+                    let (x, y) = (child.x, child.y);
+                    let element = self.build(ctx, child.element, &child_path)?;
+                    grid.add(element, x, y).map_err(|_| LoadError::Invalid {
+                        element: child_path,
+                        message: format!(
+                            "Position ({}, {}) is outside of the {}x{} grid.",
+                            x, y, columns, rows
+                        ),
+                    })?;
+                }
+                grid.to_element(id, ctx)
+            }
+        };
+
+        if let Some(layout) = description.layout {
+            layout.apply(&mut element.layout);
+        }
+        if let Some(visuals) = description.visuals {
+            element.visuals = visuals.to_visuals();
+        }
+        if let Some(hover_visuals) = description.hover_visuals {
+            element.hover_visuals = Some(hover_visuals.to_visuals());
+        }
+        if let Some(focus_visuals) = description.focus_visuals {
+            element.focus_visuals = Some(focus_visuals.to_visuals());
+        }
+        if let Some(focusable) = description.focusable {
+            element.focusable = focusable;
+        }
+        for key in description.keys {
+            let code =
+                key_from_name(&key).ok_or_else(|| invalid(format!("Unknown key {}.", key)))?;
+            element.keys.push(Some(code));
+        }
+        element.classes = description.classes;
+        if let Some(tooltip) = description.tooltip {
+            element.tooltip = Some(Box::new(self.build(
+                ctx,
+                *tooltip,
+                &format!("{}.tooltip", path),
+            )?));
+        }
+
+        Ok(element)
+    }
+}
+
+/// Watches a UI description file and rebuilds the described element tree whenever the file changes, allowing designers to iterate without restarting the game.
+/// As the ggez filesystem does not report modification times, the file is re-read in regular intervals and compared to the last version.
+#[derive(Debug)]
+pub struct UiWatcher {
+    /// The path of the watched file, relative to the ggez resource directory.
+    path: PathBuf,
+    /// The contents of the file when it was last loaded.
+    source: Option<String>,
+    /// The time between two checks of the file.
+    interval: Duration,
+    /// The time since the file was last checked.
+    elapsed: Duration,
+}
+
+impl UiWatcher {
+    /// Creates a new watcher for the file at the specified path (relative to the ggez resource directory), checking it every half second.
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        let interval = Duration::from_secs_f32(0.5);
+        Self {
+            path: path.as_ref().to_owned(),
+            source: None,
+            interval,
+            elapsed: interval,
+        }
+    }
+
+    /// Sets the time between two checks of the watched file.
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self.elapsed = interval;
+        self
+    }
+
+    /// Immediately loads the watched file and builds the described element tree. Subsequent calls to [UiWatcher::poll] only return a new tree once the file changes.
+    pub fn load<T: Copy + Eq + Hash + 'static>(
+        &mut self,
+        ctx: &Context,
+        loader: &UiLoader,
+    ) -> Result<UiElement<T>, LoadError> {
+        let source = read_source(ctx, &self.path)?;
+        self.elapsed = Duration::ZERO;
+        let result = loader.parse(ctx, &source);
+        self.source = Some(source);
+        result
+    }
+
+    /// Checks the watched file if the interval has elapsed. Call this every frame.
+    /// Returns the newly built element tree (or the error encountered while building it) if the file was changed since the last check.
+    /// If [UiWatcher::load] was not called before, the first call always loads the file.
+    pub fn poll<T: Copy + Eq + Hash + 'static>(
+        &mut self,
+        ctx: &Context,
+        loader: &UiLoader,
+    ) -> Option<Result<UiElement<T>, LoadError>> {
+        let path = self.path.clone();
+        self.check_source(ctx.time.delta(), || read_source(ctx, &path))
+            .map(|source| source.and_then(|source| loader.parse(ctx, &source)))
+    }
+
+    /// Lets the passed time pass and, if the interval has elapsed, reads the watched file with the passed function.
+    /// Returns the new contents of the file (or the error encountered while reading it) if they differ from the last version.
+    fn check_source(
+        &mut self,
+        delta: Duration,
+        read: impl FnOnce() -> Result<String, LoadError>,
+    ) -> Option<Result<String, LoadError>> {
+        self.elapsed += delta;
+        if self.elapsed < self.interval {
+            return None;
+        }
+        self.elapsed = Duration::ZERO;
+
+        match read() {
+            Err(error) => Some(Err(error)),
+            Ok(source) if self.source.as_ref() != Some(&source) => {
+                self.source = Some(source.clone());
+                Some(Ok(source))
+            }
+            Ok(_) => None,
+        }
+    }
+}
+
+/// Parses the passed JSON string to an element description.
+fn describe(source: &str) -> Result<ElementDescription, LoadError> {
+    serde_json::from_str(source).map_err(|error| LoadError::Parse {
+        line: error.line(),
+        column: error.column(),
+        message: error.to_string(),
+    })
+}
+
+/// Reads the file at the specified path (relative to the ggez resource directory) to a string.
+fn read_source(ctx: &Context, path: &Path) -> Result<String, LoadError> {
+    let mut source = String::new();
+    ctx.fs
+        .open(path)
+        .map_err(LoadError::Io)?
+        .read_to_string(&mut source)
+        .map_err(|error| LoadError::Io(GameError::from(error)))?;
+    Ok(source)
+}
+
+/// The description of a single element.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ElementDescription {
+    /// The ID of the element.
+    #[serde(default)]
+    id: u32,
+    /// The content of the element.
+    content: ContentDescription,
+    /// Changes to the default layout of the content.
+    layout: Option<LayoutDescription>,
+    /// The visuals of the element.
+    visuals: Option<VisualsDescription>,
+    /// The hover visuals of the element.
+    hover_visuals: Option<VisualsDescription>,
+    /// The focus visuals of the element.
+    focus_visuals: Option<VisualsDescription>,
+    /// The tooltip of the element.
+    tooltip: Option<Box<ElementDescription>>,
+    /// The names of the keys triggering the element.
+    #[serde(default)]
+    keys: Vec<String>,
+    /// The theme style classes of the element.
+    #[serde(default)]
+    classes: Vec<String>,
+    /// Wether the element can receive the keyboard focus.
+    focusable: Option<bool>,
+}
+
+/// The description of the content of an element.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+enum ContentDescription {
+    /// An empty element.
+    Empty,
+    /// A text element.
+    Text {
+        /// The displayed text.
+        text: String,
+        /// The font of the text.
+        font: Option<String>,
+        /// The scale of the text.
+        scale: Option<f32>,
+        /// The color of the text.
+        color: Option<ColorDescription>,
+        /// The width after which the text wraps.
+        wrap_width: Option<f32>,
+    },
+    /// An image element.
+    Image {
+        /// The path of the image file.
+        path: String,
+        /// The factor the image is scaled by.
+        scale: Option<f32>,
+    },
+    /// A sprite element, initialized from the sprite pool of the loader.
+    Sprite {
+        /// The key of the sprite in the pool.
+        path: String,
+        /// The duration (in seconds) each frame is displayed.
+        frame_time: Option<f32>,
+        /// The factor the sprite is scaled by.
+        scale: Option<f32>,
+    },
+    /// A [containers::VerticalBox].
+    VerticalBox {
+        /// The spacing between children.
+        spacing: Option<f32>,
+        /// The children of the box.
+        #[serde(default)]
+        children: Vec<ElementDescription>,
+    },
+    /// A [containers::HorizontalBox].
+    HorizontalBox {
+        /// The spacing between children.
+        spacing: Option<f32>,
+        /// The children of the box.
+        #[serde(default)]
+        children: Vec<ElementDescription>,
+    },
+    /// A [containers::StackBox].
+    StackBox {
+        /// The children of the box, the first one on top.
+        #[serde(default)]
+        children: Vec<ElementDescription>,
+    },
+    /// A [containers::GridBox].
+    GridBox {
+        /// The number of columns of the grid.
+        columns: usize,
+        /// The number of rows of the grid.
+        rows: usize,
+        /// The horizontal and vertical spacing between children.
+        spacing: Option<(f32, f32)>,
+        /// The children of the grid and their positions.
+        #[serde(default)]
+        children: Vec<GridChildDescription>,
+    },
+}
+
+/// The description of a child of a grid and its position.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GridChildDescription {
+    /// The column of the child.
+    x: usize,
+    /// The row of the child.
+    y: usize,
+    /// The child itself.
+    element: ElementDescription,
+}
+
+/// The description of changes to a [Layout].
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LayoutDescription {
+    /// The horizontal alignment.
+    x_alignment: Option<AlignmentDescription>,
+    /// The vertical alignment.
+    y_alignment: Option<AlignmentDescription>,
+    /// The horizontal offset.
+    x_offset: Option<f32>,
+    /// The vertical offset.
+    y_offset: Option<f32>,
+    /// The horizontal size.
+    x_size: Option<SizeDescription>,
+    /// The vertical size.
+    y_size: Option<SizeDescription>,
+    /// The padding.
+    padding: Option<SidesDescription>,
+    /// Wether the content preserves its ratio.
+    preserve_ratio: Option<bool>,
+}
+
+impl LayoutDescription {
+    /// Overwrites all values of the passed layout that are set in this description.
+    fn apply(self, layout: &mut Layout) {
+        if let Some(alignment) = self.x_alignment {
+            layout.x_alignment = alignment.into();
+        }
+        if let Some(alignment) = self.y_alignment {
+            layout.y_alignment = alignment.into();
+        }
+        if let Some(offset) = self.x_offset {
+            layout.x_offset = offset;
+        }
+        if let Some(offset) = self.y_offset {
+            layout.y_offset = offset;
+        }
+        if let Some(size) = self.x_size {
+            layout.x_size = size.into();
+        }
+        if let Some(size) = self.y_size {
+            layout.y_size = size.into();
+        }
+        if let Some(padding) = self.padding {
+            let [top, right, bottom, left] = padding.to_array();
+            layout.padding = (top, right, bottom, left);
+        }
+        if let Some(preserve_ratio) = self.preserve_ratio {
+            layout.preserve_ratio = preserve_ratio;
+        }
+    }
+}
+
+/// The description of an [Alignment].
+#[derive(Deserialize)]
+enum AlignmentDescription {
+    /// See [Alignment::Min].
+    Min,
+    /// See [Alignment::Center].
+    Center,
+    /// See [Alignment::Max].
+    Max,
+}
+
+impl From<AlignmentDescription> for Alignment {
+    fn from(value: AlignmentDescription) -> Self {
+        match value {
+            AlignmentDescription::Min => Alignment::Min,
+            AlignmentDescription::Center => Alignment::Center,
+            AlignmentDescription::Max => Alignment::Max,
+        }
+    }
+}
+
+/// The description of a [Size]. Missing maximum values are infinite.
+#[derive(Deserialize)]
+enum SizeDescription {
+    /// See [Size::Fixed].
+    Fixed(f32),
+    /// See [Size::Fill].
+    Fill(f32, Option<f32>),
+    /// See [Size::Shrink].
+    Shrink(f32, Option<f32>),
+}
+
+impl From<SizeDescription> for Size {
+    fn from(value: SizeDescription) -> Self {
+        match value {
+            SizeDescription::Fixed(size) => Size::Fixed(size),
+            SizeDescription::Fill(min, max) => Size::Fill(min, max.unwrap_or(f32::INFINITY)),
+            SizeDescription::Shrink(min, max) => Size::Shrink(min, max.unwrap_or(f32::INFINITY)),
+        }
+    }
+}
+
+/// The description of [Visuals].
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct VisualsDescription {
+    /// The background color.
+    background: ColorDescription,
+    /// The border color, black if not set.
+    border: Option<ColorDescription>,
+    /// The border widths.
+    #[serde(default)]
+    border_width: SidesDescription,
+    /// The corner radii.
+    #[serde(default)]
+    corner_radius: SidesDescription,
+}
+
+impl VisualsDescription {
+    /// Converts this description to actual visuals.
+    fn to_visuals(&self) -> Visuals {
+        Visuals::new_custom(
+            self.background.to_color(),
+            self.border
+                .as_ref()
+                .map(ColorDescription::to_color)
+                .unwrap_or(Color::BLACK),
+            self.border_width.to_array(),
+            self.corner_radius.to_array(),
+        )
+    }
+}
+
+/// The description of a value for each side (or corner) of an element, either as a single number or as an array of four.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SidesDescription {
+    /// The same value for all sides.
+    All(f32),
+    /// One value per side.
+    Each([f32; 4]),
+}
+
+impl Default for SidesDescription {
+    fn default() -> Self {
+        Self::All(0.)
+    }
+}
+
+impl SidesDescription {
+    /// Returns the value of each side.
+    fn to_array(&self) -> [f32; 4] {
+        match self {
+            SidesDescription::All(value) => [*value; 4],
+            SidesDescription::Each(values) => *values,
+        }
+    }
+}
+
+/// The description of a [Color], with or without alpha value.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ColorDescription {
+    /// An opaque color.
+    Rgb([u8; 3]),
+    /// A color with alpha value.
+    Rgba([u8; 4]),
+}
+
+impl ColorDescription {
+    /// Converts this description to an actual color.
+    fn to_color(&self) -> Color {
+        match *self {
+            ColorDescription::Rgb([r, g, b]) => Color::from_rgb(r, g, b),
+            ColorDescription::Rgba([r, g, b, a]) => Color::from_rgba(r, g, b, a),
+        }
+    }
+}
+
+/// Generates [key_from_name], mapping the names of the listed key codes to the key codes.
+macro_rules! key_names {
+    ($($key:ident),* $(,)?) => {
+        /// Returns the [VirtualKeyCode] with the passed name, if it is one of the commonly used keys.
+        fn key_from_name(name: &str) -> Option<VirtualKeyCode> {
+            match name {
+                $(stringify!($key) => Some(VirtualKeyCode::$key),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+key_names!(
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+    I,
+    J,
+    K,
+    L,
+    M,
+    N,
+    O,
+    P,
+    Q,
+    R,
+    S,
+    T,
+    U,
+    V,
+    W,
+    X,
+    Y,
+    Z,
+    Key0,
+    Key1,
+    Key2,
+    Key3,
+    Key4,
+    Key5,
+    Key6,
+    Key7,
+    Key8,
+    Key9,
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
+    Escape,
+    Return,
+    Space,
+    Tab,
+    Back,
+    Delete,
+    Insert,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    Left,
+    Right,
+    Up,
+    Down,
+    Numpad0,
+    Numpad1,
+    Numpad2,
+    Numpad3,
+    Numpad4,
+    Numpad5,
+    Numpad6,
+    Numpad7,
+    Numpad8,
+    Numpad9,
+    NumpadEnter,
+    LShift,
+    RShift,
+    LControl,
+    RControl,
+    LAlt,
+    RAlt,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A description using every content and property that can be checked without a context.
+    const VALID: &str = r#"{
+        "content": {"VerticalBox": {"spacing": 5, "children": [
+            {"id": 1, "content": {"Text": {"text": "Start", "scale": 32, "color": [255, 255, 255]}},
+                "keys": ["Return"], "focusable": true, "classes": ["button"],
+                "tooltip": {"content": {"Text": {"text": "Starts the game."}}}},
+            {"content": {"GridBox": {"columns": 2, "rows": 1, "spacing": [4, 4], "children": [
+                {"x": 0, "y": 0, "element": {"content": "Empty"}},
+                {"x": 1, "y": 0, "element": {"id": 2, "content": {"StackBox": {"children": []}},
+                    "layout": {"x_size": {"Fill": [0, null]}, "padding": 3}}}
+            ]}}},
+            {"content": {"HorizontalBox": {}}, "visuals": {"background": [0, 0, 0, 128], "corner_radius": [1, 2, 3, 4]}}
+        ]}}
+    }"#;
+
+    /// Returns the path of the invalid element if checking the passed source fails with [LoadError::Invalid].
+    fn invalid_element(source: &str) -> Option<String> {
+        match UiLoader::new().check(source) {
+            Err(LoadError::Invalid { element, .. }) => Some(element),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn valid_tree() {
+        assert!(UiLoader::new().check(VALID).is_ok());
+    }
+
+    #[test]
+    fn parse_error() {
+        let source = "{\n  \"content\": \"Empty\",\n  \"idd\": 1\n}";
+        match UiLoader::new().check(source) {
+            Err(LoadError::Parse { line, column, .. }) => assert_eq!((line, column), (3, 7)),
+            other => panic!("Expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn grid_position() {
+        let source = r#"{"content": {"VerticalBox": {"children": [
+            {"content": "Empty"},
+            {"content": {"GridBox": {"columns": 2, "rows": 2, "children": [
+                {"x": 1, "y": 1, "element": {"content": "Empty"}},
+                {"x": 0, "y": 2, "element": {"content": "Empty"}}
+            ]}}}
+        ]}}}"#;
+        assert_eq!(
+            invalid_element(source).as_deref(),
+            Some("root.children[1].children[1]")
+        );
+    }
+
+    #[test]
+    fn unknown_key() {
+        let source = r#"{"content": {"HorizontalBox": {"children": [
+            {"content": "Empty", "tooltip": {"content": "Empty", "keys": ["A", "Hyper"]}}
+        ]}}}"#;
+        assert_eq!(
+            invalid_element(source).as_deref(),
+            Some("root.children[0].tooltip")
+        );
+    }
+
+    #[test]
+    fn sprite_without_pool() {
+        let source = r#"{"content": {"Sprite": {"path": "/moo-sheet"}}}"#;
+        assert_eq!(invalid_element(source).as_deref(), Some("root"));
+    }
+
+    #[test]
+    fn watcher_changes() {
+        let mut watcher = UiWatcher::new("/ui.json").with_interval(Duration::from_secs(1));
+        let read = |source: &str| {
+            let source = source.to_owned();
+            move || Ok(source)
+        };
+
+        // the first check always reads the file
+        let first = watcher.check_source(Duration::ZERO, read("a"));
+        assert_eq!(first.and_then(Result::ok).as_deref(), Some("a"));
+
+        // the file is not read again before the interval has elapsed
+        assert!(watcher
+            .check_source(Duration::from_millis(600), || panic!("Read too early."))
+            .is_none());
+
+        // unchanged files are not reported, changed ones are
+        assert!(watcher
+            .check_source(Duration::from_millis(600), read("a"))
+            .is_none());
+        let changed = watcher.check_source(Duration::from_secs(1), read("b"));
+        assert_eq!(changed.and_then(Result::ok).as_deref(), Some("b"));
+
+        // errors are reported every time
+        let error = || Err(LoadError::Io(GameError::CustomError("Missing".to_owned())));
+        assert!(matches!(
+            watcher.check_source(Duration::from_secs(1), error),
+            Some(Err(LoadError::Io(_)))
+        ));
+        assert!(matches!(
+            watcher.check_source(Duration::from_secs(1), error),
+            Some(Err(LoadError::Io(_)))
+        ));
+    }
+}
@@ -1,7 +1,8 @@
-use std::hash::Hash;
+use std::hash::{Hash, Hasher};
 
 /// A simple enum that specififes what kind of messages a UI element can send or receive.
-#[derive(Eq, Hash, PartialEq, Clone, Copy, Debug)]
+/// Not [Copy], as [UiMessage::ValueChanged] may carry text. Compare messages by reference or clone them.
+#[derive(Eq, Hash, PartialEq, Clone, Debug)]
 pub enum UiMessage<T: Copy + Eq + Hash + Hash> {
     /// An extern message, containing another Message of the specified type used by your gamestate. Never sent by elements on their own.
     Extern(T),
@@ -9,8 +10,94 @@ pub enum UiMessage<T: Copy + Eq + Hash + Hash> {
     Clicked(u32),
     /// A struct that is sent by an element when it is clicked using the right mouse button, containing its ID. Elements with ID 0 will not send such messages.
     ClickedRight(u32),
+    /// A struct that is sent by an element when it is clicked using the middle mouse button, containing its ID. Elements with ID 0 will not send such messages.
+    MiddleClicked(u32),
+    /// A struct that is sent by an element when it is clicked twice in quick succession using the left mouse button, containing its ID. Elements with ID 0 will not send such messages.
+    /// The second click also sends a normal [UiMessage::Clicked].
+    DoubleClicked(u32),
+    /// A struct that is sent by an element when the left mouse button is released after being pressed on the element (no matter where the cursor is now), containing its ID. Elements with ID 0 will not send such messages.
+    MouseReleased(u32),
+    /// A struct that is sent by an element when the mouse cursor starts hovering above it, containing its ID. Elements with ID 0 will not send such messages.
+    HoverStart(u32),
+    /// A struct that is sent by an element when the mouse cursor stops hovering above it, containing its ID. Elements with ID 0 will not send such messages.
+    HoverEnd(u32),
+    /// A struct that is sent by an element when the mouse wheel is used above it, containing its ID and the number of steps scrolled (positive values meaning upwards).
+    /// Elements with ID 0 will not send such messages. Requires mouse wheel events to be passed to the UI via [super::UiElement::mouse_wheel_event].
+    Scrolled(u32, i32),
+    /// A struct that is sent by an element when the mouse is moved by a few pixels while the left mouse button is held after being pressed on the element, containing its ID. Elements with ID 0 will not send such messages.
+    DragStarted(u32),
+    /// A struct that is sent by an element on every frame the mouse moves during a drag started on it (see [UiMessage::DragStarted]), containing its ID. Elements with ID 0 will not send such messages.
+    Dragged(u32),
+    /// A struct that is sent by an element when the left mouse button is released during a drag started on it (see [UiMessage::DragStarted]), containing its ID. Elements with ID 0 will not send such messages.
+    DragEnded(u32),
+    /// A struct that is sent when an element carrying a drag payload is dropped onto an element accepting that payload.
+    /// Contains the IDs of the dragged element and the element it was dropped on as well as the payload.
+    /// The dragged element is not moved automatically, use [super::UiElement::remove_elements] and [super::UiElement::add_element] to do so.
+    DragDropped {
+        /// The ID of the dragged element.
+        source: u32,
+        /// The ID of the element the dragged element was dropped on.
+        target: u32,
+        /// The payload of the dragged element.
+        payload: T,
+    },
     /// A struct that is sent by an element when one of its registered keys are pressed, containing its ID. Elements with ID 0 will not send such messages.
     PressedKey(u32),
-    /// A struct that is sent if an element is 'triggered' in any way (key press or click) in addition to the specific event as above
+    /// A struct that is sent by an element when one of its registered gamepad buttons is pressed or it is activated via gamepad while focused, containing its ID. Elements with ID 0 will not send such messages.
+    PressedGamepadButton(u32),
+    /// A struct that is sent if an element is 'triggered' in any way (key press, gamepad button press or click) in addition to the specific event as above
     Triggered(u32),
+    /// A struct that is sent by an element containing editable text when that text is changed by the user, containing its ID. Elements with ID 0 will not send such messages.
+    TextChanged(u32),
+    /// A struct that is sent by an element when one of its transitions completes, containing its ID. Elements with ID 0 will not send such messages.
+    /// Sent during the first call of [super::UiElement::update] after the transition completed.
+    TransitionFinished(u32),
+    /// A struct that is sent by an element containing editable text when the user submits that text (usually by pressing enter), containing its ID. Elements with ID 0 will not send such messages.
+    TextSubmitted(u32),
+    /// A struct that is sent by an element whose value (see [super::UiElement::get_value]) is changed by the user, containing its ID and the new value. Elements with ID 0 will not send such messages.
+    ValueChanged(u32, UiValue),
+}
+
+/// The value of an element the user can change, such as the text of a [super::basic::TextInput], as sent with [UiMessage::ValueChanged] and returned by [super::UiElement::get_value].
+/// Floats are compared bitwise, so values can be used within messages as keys of a hash set.
+#[derive(Clone, Debug)]
+pub enum UiValue {
+    /// A value that is either on or off, e.g. of a checkbox.
+    Bool(bool),
+    /// A whole number, e.g. of a counter.
+    Int(i64),
+    /// A decimal number, e.g. of a slider.
+    Float(f32),
+    /// A text, e.g. of a text field.
+    Text(String),
+    /// The index of the selected option, e.g. of a dropdown or a group of radio buttons.
+    Selection(usize),
+}
+
+impl PartialEq for UiValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Bool(a), Self::Bool(b)) => a == b,
+            (Self::Int(a), Self::Int(b)) => a == b,
+            (Self::Float(a), Self::Float(b)) => a.to_bits() == b.to_bits(),
+            (Self::Text(a), Self::Text(b)) => a == b,
+            (Self::Selection(a), Self::Selection(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for UiValue {}
+
+impl Hash for UiValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Bool(value) => value.hash(state),
+            Self::Int(value) => value.hash(state),
+            Self::Float(value) => value.to_bits().hash(state),
+            Self::Text(value) => value.hash(state),
+            Self::Selection(value) => value.hash(state),
+        }
+    }
 }
@@ -0,0 +1,109 @@
+use std::{collections::HashSet, hash::Hash, time::Duration};
+
+use ggez::{event::MouseButton, glam::Vec2};
+
+use super::{InputState, UiMessage};
+
+/// The maximum time between two clicks on the same element to count as a double click.
+const DOUBLE_CLICK_TIME: Duration = Duration::from_millis(400);
+/// The distance (in pixels) the mouse needs to move while pressed on an element before a drag is started.
+const DRAG_THRESHOLD: f32 = 4.;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// This struct remembers the interactions of the mouse with a UiElement across frames, to detect hovering, double clicks, drags and scrolling.
+pub struct MouseState {
+    /// Wether the mouse cursor was above the element during the last frame.
+    hovered: bool,
+    /// The position the left mouse button was pressed at, if it was pressed on the element and is still held.
+    press_position: Option<Vec2>,
+    /// Wether the element is currently being dragged.
+    dragging: bool,
+    /// The time (since the start of the game) of the last click on the element that did not complete a double click.
+    last_click: Option<Duration>,
+    /// The mouse wheel movement above the element not yet sent as a message.
+    scroll: f32,
+}
+
+impl MouseState {
+    /// Adds the passed vertical mouse wheel movement to the scroll amount to be sent with the next message collection.
+    pub fn add_scroll(&mut self, y: f32) {
+        self.scroll += y;
+    }
+
+    /// Returns wether the element is currently being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+
+    /// Returns the distance between the passed mouse position and the position the current drag started at, if the element is being dragged.
+    pub fn drag_offset(&self, mouse: Vec2) -> Option<Vec2> {
+        self.press_position
+            .filter(|_| self.dragging)
+            .map(|press_position| mouse - press_position)
+    }
+
+    /// Updates this state with the passed mouse input of the last frame and adds the resulting messages to the passed set.
+    /// `hovered` needs to contain wether the mouse cursor is currently above the element (or one of its successors) without being covered by another element.
+    /// `target` needs to contain wether the element is the one receiving mouse input (clicks, drags, scrolling).
+    pub fn collect_messages<T: Copy + Eq + Hash>(
+        &mut self,
+        input: &InputState,
+        id: u32,
+        hovered: bool,
+        target: bool,
+        res: &mut HashSet<UiMessage<T>>,
+    ) {
+        // hovering
+        if hovered && !self.hovered {
+            res.insert(UiMessage::HoverStart(id));
+        } else if !hovered && self.hovered {
+            res.insert(UiMessage::HoverEnd(id));
+        }
+        self.hovered = hovered;
+
+        // clicks
+        if target && input.button_just_pressed(MouseButton::Left) {
+            let now = input.time_since_start;
+            match self.last_click {
+                Some(last) if now.saturating_sub(last) <= DOUBLE_CLICK_TIME => {
+                    res.insert(UiMessage::DoubleClicked(id));
+                    self.last_click = None;
+                }
+                _ => self.last_click = Some(now),
+            }
+            self.press_position = Some(input.mouse_position);
+        }
+
+        if target && input.button_just_pressed(MouseButton::Middle) {
+            res.insert(UiMessage::MiddleClicked(id));
+        }
+
+        // dragging
+        if let Some(press_position) = self.press_position {
+            let position = input.mouse_position;
+            if !self.dragging && position.distance(press_position) >= DRAG_THRESHOLD {
+                self.dragging = true;
+                res.insert(UiMessage::DragStarted(id));
+            } else if self.dragging && input.mouse_delta != Vec2::ZERO {
+                res.insert(UiMessage::Dragged(id));
+            }
+        }
+
+        // releasing
+        if self.press_position.is_some() && !input.button_pressed(MouseButton::Left) {
+            res.insert(UiMessage::MouseReleased(id));
+            if self.dragging {
+                res.insert(UiMessage::DragEnded(id));
+            }
+            self.press_position = None;
+            self.dragging = false;
+        }
+
+        // scrolling, only full steps are sent
+        let steps = self.scroll.trunc();
+        if steps != 0. {
+            res.insert(UiMessage::Scrolled(id, steps as i32));
+            self.scroll -= steps;
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use std::{fmt, fs, path::Path};
+
+use ggez::graphics::Rect;
+
+use super::LayoutTree;
+
+/// The environment variable that makes [LayoutTree::assert_snapshot] overwrite stored snapshots instead of comparing against them.
+pub const UPDATE_SNAPSHOTS_VAR: &str = "MOOEYE_UPDATE_SNAPSHOTS";
+
+/// Writes a rectangle as `[x, y, w, h]`, rounded to one decimal place.
+fn write_rect(f: &mut fmt::Formatter<'_>, rect: Rect) -> fmt::Result {
+    // adding 0 turns -0.0 into 0.0, so rounding errors around 0 do not change the output
+    write!(
+        f,
+        "[{:.1}, {:.1}, {:.1}, {:.1}]",
+        rect.x + 0.,
+        rect.y + 0.,
+        rect.w + 0.,
+        rect.h + 0.
+    )
+}
+
+impl LayoutTree {
+    /// Writes this layout and those of all children, indenting each element by its depth in the tree.
+    fn write_indented(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        write!(
+            f,
+            "{}#{} {} outer: ",
+            "  ".repeat(depth),
+            self.id,
+            self.content
+        )?;
+        write_rect(f, self.outer)?;
+        write!(f, " inner: ")?;
+        write_rect(f, self.inner)?;
+        if !self.fits {
+            write!(f, " (does not fit)")?;
+        }
+        writeln!(f)?;
+        for child in &self.children {
+            child.write_indented(f, depth + 1)?;
+        }
+        Ok(())
+    }
+
+    /// Compares the text representation of this layout to the snapshot stored at the passed path and panics with a line-by-line diff if they differ.
+    /// If the environment variable [UPDATE_SNAPSHOTS_VAR] (`MOOEYE_UPDATE_SNAPSHOTS`) is set, the snapshot is overwritten with this layout instead.
+    /// Missing snapshots are written as well, but still fail the comparison so they do not go unnoticed.
+    /// The path is relative to the working directory, which is the package root when running `cargo test`.
+    pub fn assert_snapshot(&self, path: impl AsRef<Path>) {
+        let path = path.as_ref();
+        let actual = self.to_string();
+        let update = std::env::var_os(UPDATE_SNAPSHOTS_VAR).is_some_and(|value| value != "0");
+
+        let expected = match fs::read_to_string(path) {
+            Ok(expected) if !update => expected,
+            result => {
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent).unwrap_or_else(|error| {
+                        panic!(
+                            "Could not create snapshot directory {:?}: {}",
+                            parent, error
+                        )
+                    });
+                }
+                fs::write(path, &actual).unwrap_or_else(|error| {
+                    panic!("Could not write snapshot {:?}: {}", path, error)
+                });
+                if result.is_err() && !update {
+                    panic!(
+                        "Snapshot {:?} did not exist and was created. Review it and run the test again.",
+                        path
+                    );
+                }
+                return;
+            }
+        };
+
+        // line endings may have been changed by version control
+        let expected = expected.replace("\r\n", "\n");
+        if expected != actual {
+            let expected_lines: Vec<&str> = expected.lines().collect();
+            let actual_lines: Vec<&str> = actual.lines().collect();
+            let mut diff = String::new();
+            for index in 0..expected_lines.len().max(actual_lines.len()) {
+                match (expected_lines.get(index), actual_lines.get(index)) {
+                    (Some(e), Some(a)) if e == a => diff += &format!("  {}\n", e),
+                    (e, a) => {
+                        if let Some(e) = e {
+                            diff += &format!("- {}\n", e);
+                        }
+                        if let Some(a) = a {
+                            diff += &format!("+ {}\n", a);
+                        }
+                    }
+                }
+            }
+            panic!(
+                "Layout does not match snapshot {:?} (- expected, + actual):\n{}Set {}=1 to update the snapshot.",
+                path, diff, UPDATE_SNAPSHOTS_VAR
+            );
+        }
+    }
+}
+
+/// Writes one line per element in the format `#id Content outer: [x, y, w, h] inner: [x, y, w, h]`, children indented below their parent.
+/// Elements that do not fit into their target are marked with `(does not fit)`.
+impl fmt::Display for LayoutTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_indented(f, 0)
+    }
+}
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+
+use ggez::audio::SoundData;
+
+use super::Visuals;
+
+/// A named style class of a [Theme]. All values are optional, elements using this style keep their own values (those they had before any theme was applied) for values not set.
+/// Fonts and text scales are applied to the contents of elements and stay until another style sets them.
+#[derive(Clone, Debug, Default)]
+pub struct Style {
+    /// The visuals of elements using this style.
+    pub visuals: Option<Visuals>,
+    /// The hover visuals of elements using this style.
+    pub hover_visuals: Option<Visuals>,
+    /// The focus visuals of elements using this style.
+    pub focus_visuals: Option<Visuals>,
+    /// The padding of elements using this style, in the order top, right, bottom, left.
+    pub padding: Option<(f32, f32, f32, f32)>,
+    /// The font of text displayed by elements using this style. The font has to be registered with ggez via [ggez::graphics::GraphicsContext::add_font].
+    pub font: Option<String>,
+    /// The scale (text height in pixels) of text displayed by elements using this style.
+    pub text_scale: Option<f32>,
+    /// The sound played when elements using this style are triggered.
+    pub trigger_sound: Option<SoundData>,
+}
+
+impl Style {
+    /// Creates a new style that does not change anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the visuals of elements using this style.
+    pub fn with_visuals(mut self, visuals: Visuals) -> Self {
+        self.visuals = Some(visuals);
+        self
+    }
+
+    /// Sets the hover visuals of elements using this style.
+    pub fn with_hover_visuals(mut self, hover_visuals: Visuals) -> Self {
+        self.hover_visuals = Some(hover_visuals);
+        self
+    }
+
+    /// Sets the focus visuals of elements using this style.
+    pub fn with_focus_visuals(mut self, focus_visuals: Visuals) -> Self {
+        self.focus_visuals = Some(focus_visuals);
+        self
+    }
+
+    /// Sets the padding of elements using this style, in the order top, right, bottom, left.
+    pub fn with_padding(mut self, padding: (f32, f32, f32, f32)) -> Self {
+        self.padding = Some(padding);
+        self
+    }
+
+    /// Sets the font of text displayed by elements using this style.
+    pub fn with_font(mut self, font: impl Into<String>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the scale (text height in pixels) of text displayed by elements using this style.
+    pub fn with_text_scale(mut self, text_scale: f32) -> Self {
+        self.text_scale = Some(text_scale);
+        self
+    }
+
+    /// Sets the sound played when elements using this style are triggered.
+    pub fn with_trigger_sound(mut self, trigger_sound: SoundData) -> Self {
+        self.trigger_sound = Some(trigger_sound);
+        self
+    }
+}
+
+/// A collection of named [Style]s. Elements reference styles by name via [super::UiElementBuilder::with_class].
+/// Apply a theme to an element tree with [super::UiElementBuilder::with_theme] when building or [super::UiElement::apply_theme] at any time.
+/// Applying another theme later re-skins the entire UI.
+#[derive(Clone, Debug, Default)]
+pub struct Theme {
+    /// The styles of this theme, indexed by their class names.
+    classes: HashMap<String, Style>,
+}
+
+impl Theme {
+    /// Creates a new theme without any style classes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a style class with the specified name to this theme, replacing any class with the same name.
+    pub fn with_class(mut self, name: impl Into<String>, style: Style) -> Self {
+        self.classes.insert(name.into(), style);
+        self
+    }
+
+    /// Returns the style class with the specified name, if this theme contains it.
+    pub fn get(&self, name: &str) -> Option<&Style> {
+        self.classes.get(name)
+    }
+
+    /// Returns a mutable reference to the style class with the specified name, if this theme contains it.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Style> {
+        self.classes.get_mut(name)
+    }
+}
+
+/// The values of the style-driven fields of an element before any theme was applied to it.
+/// They are restored before every theme is applied, so values set by a previous theme do not remain.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Unthemed {
+    /// The visuals of the element.
+    pub(crate) visuals: Visuals,
+    /// The hover visuals of the element.
+    pub(crate) hover_visuals: Option<Visuals>,
+    /// The focus visuals of the element.
+    pub(crate) focus_visuals: Option<Visuals>,
+    /// The padding of the element.
+    pub(crate) padding: (f32, f32, f32, f32),
+}
+
+#[cfg(test)]
+mod tests {
+    use ggez::graphics::Color;
+
+    use super::*;
+    use crate::ui::{UiElement, UiElementBuilder};
+
+    /// Returns plain visuals with the passed background color.
+    fn visuals(background: Color) -> Visuals {
+        Visuals::new(background, Color::BLACK, 1., 0.)
+    }
+
+    // applying a theme completely needs a context (for sounds and text contents), so these tests apply the element values only
+
+    #[test]
+    fn switch_themes() {
+        let mut element: UiElement<()> = UiElementBuilder::new(1, ())
+            .with_class("button")
+            .with_visuals(visuals(Color::WHITE))
+            .with_padding((1., 1., 1., 1.))
+            .build();
+
+        let first = Theme::new().with_class(
+            "button",
+            Style::new()
+                .with_visuals(visuals(Color::RED))
+                .with_hover_visuals(visuals(Color::GREEN))
+                .with_focus_visuals(visuals(Color::BLUE))
+                .with_padding((5., 5., 5., 5.)),
+        );
+        let second =
+            Theme::new().with_class("button", Style::new().with_visuals(visuals(Color::YELLOW)));
+
+        assert_eq!(element.apply_theme_fields(&first).len(), 1);
+        assert_eq!(element.visuals, visuals(Color::RED));
+        assert_eq!(element.hover_visuals, Some(visuals(Color::GREEN)));
+        assert_eq!(element.focus_visuals, Some(visuals(Color::BLUE)));
+        assert_eq!(element.layout.padding, (5., 5., 5., 5.));
+
+        // values the second theme does not set are reset instead of kept from the first
+        element.apply_theme_fields(&second);
+        assert_eq!(element.visuals, visuals(Color::YELLOW));
+        assert_eq!(element.hover_visuals, None);
+        assert_eq!(element.focus_visuals, None);
+        assert_eq!(element.layout.padding, (1., 1., 1., 1.));
+
+        // and back again
+        element.apply_theme_fields(&first);
+        assert_eq!(element.hover_visuals, Some(visuals(Color::GREEN)));
+        assert!(element.apply_theme_fields(&Theme::new()).is_empty());
+        assert_eq!(element.visuals, visuals(Color::WHITE));
+        assert_eq!(element.layout.padding, (1., 1., 1., 1.));
+    }
+
+    #[test]
+    fn values_set_after_theme() {
+        let theme = Theme::new().with_class(
+            "button",
+            Style::new()
+                .with_visuals(visuals(Color::RED))
+                .with_padding((5., 5., 5., 5.)),
+        );
+        let mut element: UiElement<()> = UiElementBuilder::new(1, ()).with_class("button").build();
+        element.apply_theme_fields(&theme);
+
+        // values set by the builder after a theme overwrite the theme and are kept as the values of the element
+        let mut element = UiElementBuilder::from(element)
+            .with_padding((2., 2., 2., 2.))
+            .build();
+        assert_eq!(element.layout.padding, (2., 2., 2., 2.));
+        element.apply_theme_fields(&Theme::new());
+        assert_eq!(element.visuals, Visuals::default());
+        assert_eq!(element.layout.padding, (2., 2., 2., 2.));
+    }
+}
@@ -1,13 +1,40 @@
 use std::{hash::Hash, time::Duration};
 
-use ggez::graphics::Rect;
+use ggez::graphics::{Color, Rect};
 
 use crate::ui::{UiContent, UiElement};
 
-use super::{Layout, Visuals};
+use super::{Easing, Layout, Size, UiTransform, Visuals};
+
+/// The channels a [Transition] can occupy. Transitions in the queue of an element progress in parallel as long as they do not share a channel with a transition queued before them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TransitionChannel {
+    /// Changes to the layout and the transform of an element.
+    Layout,
+    /// Changes to the visuals, opacity and tint of an element.
+    Visuals,
+    /// Changes to the hover visuals of an element.
+    HoverVisuals,
+    /// Changes to the content of an element.
+    Content,
+    /// Changes to the tooltip of an element.
+    Tooltip,
+}
+
+impl TransitionChannel {
+    /// All channels a transition can occupy.
+    pub const ALL: [TransitionChannel; 5] = [
+        TransitionChannel::Layout,
+        TransitionChannel::Visuals,
+        TransitionChannel::HoverVisuals,
+        TransitionChannel::Content,
+        TransitionChannel::Tooltip,
+    ];
+}
 
 /// A Transition stuct that can be added to an UiElement to slowly change that elements properties over time.
 /// A transition can change the elements layout, visuals, hover_visuals, content and tooltip by first augmenting the transition with the relevant methods.
+/// The properties a transition changes determine the [TransitionChannel]s it occupies, so e.g. a layout transition and a visuals transition run at the same time.
 pub struct Transition<T: Copy + Eq + Hash> {
     /// The layout transitioned to.
     pub(crate) new_layout: Option<Layout>,
@@ -15,15 +42,29 @@ pub struct Transition<T: Copy + Eq + Hash> {
     pub(crate) new_visuals: Option<Visuals>,
     /// The hover visuals transitioned to.
     pub(crate) new_hover_visuals: Option<Option<Visuals>>,
+    /// The opacity transitioned to.
+    pub(crate) new_opacity: Option<f32>,
+    /// The tint transitioned to.
+    pub(crate) new_tint: Option<Color>,
+    /// The transform transitioned to.
+    pub(crate) new_transform: Option<UiTransform>,
     /// The content transitioned to.
     pub(crate) new_content: Option<Box<dyn UiContent<T>>>,
     /// The tooltip transitioned to.
     pub(crate) new_tooltip: Option<Option<Box<UiElement<T>>>>,
+    /// Wether the new content fades in over the duration of this transition while the old content fades out, instead of replacing it at the end.
+    pub(crate) crossfade: bool,
+    /// The sizes of the element with the new content, as measured once a cross-fade starts.
+    pub(crate) new_content_size: Option<(Size, Size)>,
+    /// The message sent as [super::UiMessage::Extern] once this transition completes.
+    pub(crate) completion_message: Option<T>,
 
     /// The total duration this transition will take.
     total_duration: Duration,
     /// The duration that has already passed with this transition active.
     progressed_duration: Duration,
+    /// The easing curve applied to the progress of this transition.
+    easing: Easing,
 }
 
 impl<T: Copy + Eq + Hash> std::fmt::Debug for Transition<T> {
@@ -32,25 +73,39 @@ impl<T: Copy + Eq + Hash> std::fmt::Debug for Transition<T> {
             .field("new_layout", &self.new_layout)
             .field("new_visuals", &self.new_visuals)
             .field("new_hover_visuals", &self.new_hover_visuals)
+            .field("new_opacity", &self.new_opacity)
+            .field("new_tint", &self.new_tint)
+            .field("new_transform", &self.new_transform)
             .field("new_tooltip", &self.new_tooltip)
+            .field("crossfade", &self.crossfade)
+            .field("new_content_size", &self.new_content_size)
             .field("total_duration", &self.total_duration)
             .field("progressed_duration", &self.progressed_duration)
+            .field("easing", &self.easing)
             .finish()
     }
 }
 
 impl<T: Copy + Eq + Hash> Transition<T> {
-    /// Creates a new transition with the specified duration and all possible augmentations set to none. Can be used without adding changes to delay transitions added later.
+    /// Creates a new transition with the specified duration and all possible augmentations set to none.
+    /// Can be used without adding changes to delay transitions added later, as a transition without changes occupies all channels.
     pub fn new(duration: Duration) -> Self {
         Self {
             new_layout: None,
             new_visuals: None,
             new_hover_visuals: None,
+            new_opacity: None,
+            new_tint: None,
+            new_transform: None,
             new_content: None,
             new_tooltip: None,
+            crossfade: false,
+            new_content_size: None,
+            completion_message: None,
 
             total_duration: duration,
             progressed_duration: Duration::ZERO,
+            easing: Easing::Linear,
         }
     }
 
@@ -73,6 +128,31 @@ impl<T: Copy + Eq + Hash> Transition<T> {
         self
     }
 
+    /// Augments this transition to now (gradually) change the opacity of the UiElement it is added to (and thus its children), fading it in or out smoothly.
+    pub fn with_new_opacity(mut self, new_opacity: f32) -> Self {
+        self.new_opacity = Some(new_opacity);
+        self
+    }
+
+    /// Augments this transition to now (gradually) change the tint of the UiElement it is added to (and thus its children), blending over smoothly.
+    pub fn with_new_tint(mut self, new_tint: Color) -> Self {
+        self.new_tint = Some(new_tint);
+        self
+    }
+
+    /// Augments this transition to now (gradually) change the transform of the UiElement it is added to (and thus its children), scaling, rotating and moving it smoothly.
+    /// Combine this with [super::Easing::BackOut] or [super::Easing::ElasticOut] for a popping effect.
+    pub fn with_new_transform(mut self, new_transform: UiTransform) -> Self {
+        self.new_transform = Some(new_transform);
+        self
+    }
+
+    /// Sets the easing curve of this transition, controlling how layout, visuals, hover visuals, opacity, tint and transform blend over time. Default is [Easing::Linear].
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
     /// Augment this transition to now change the content of the UiElement it is added to. This change happens in a single frame as soon as the transitions duration has elapsed.
     pub fn with_new_content<E>(mut self, new_content: E) -> Self
     where
@@ -82,24 +162,71 @@ impl<T: Copy + Eq + Hash> Transition<T> {
         self
     }
 
+    /// Sets wether the new content of this transition (if any) cross-fades with the old content.
+    /// If set, both contents are drawn with complementary opacity over the duration of the transition and the size of the element blends from the old to the new content.
+    /// The new content is measured (just as when converting it to an element) once the cross-fade starts, and the element keeps the measured size after the transition completes.
+    /// Otherwise (the default), the content is replaced in a single frame once the duration has elapsed.
+    pub fn with_crossfade(mut self, crossfade: bool) -> Self {
+        self.crossfade = crossfade;
+        self
+    }
+
     /// Augment this transition to now change the tooltip of the UiElement it is added to. This change happens in a single frame as soon as the transitions duration has elapsed.
     pub fn with_new_tooltip(mut self, new_tooltip: Option<UiElement<T>>) -> Self {
         self.new_tooltip = new_tooltip.map(|element| Some(Box::new(element)));
         self
     }
 
+    /// Returns wether this transition occupies the specified channel, i.e. changes any of the properties belonging to it.
+    /// A transition that does not change anything occupies all channels.
+    pub fn affects(&self, channel: TransitionChannel) -> bool {
+        let changes = |channel: TransitionChannel| match channel {
+            TransitionChannel::Layout => self.new_layout.is_some() || self.new_transform.is_some(),
+            TransitionChannel::Visuals => {
+                self.new_visuals.is_some() || self.new_opacity.is_some() || self.new_tint.is_some()
+            }
+            TransitionChannel::HoverVisuals => self.new_hover_visuals.is_some(),
+            TransitionChannel::Content => self.new_content.is_some(),
+            TransitionChannel::Tooltip => self.new_tooltip.is_some(),
+        };
+        changes(channel) || !TransitionChannel::ALL.into_iter().any(changes)
+    }
+
+    /// Augments this transition to send the specified message as [super::UiMessage::Extern] once it completes, e.g. to switch scenes after the UI has faded out.
+    /// The message is sent during the first call of [UiElement::update] after completion, along with a [super::UiMessage::TransitionFinished].
+    pub fn with_completion_message(mut self, message: T) -> Self {
+        self.completion_message = Some(message);
+        self
+    }
+
     /// Progresses the internal timer of this transition by the specified amount. Returns true if the Transition is now complete and false otherwise.
     pub(crate) fn progress(&mut self, delta: Duration) -> bool {
         self.progressed_duration += delta;
         self.progressed_duration >= self.total_duration
     }
 
-    /// Returns a float in between 0. and 1. describung how much of this transitions total duration has elapsed already.
+    /// Returns a float describing how much of this transitions total duration has elapsed already, with this transitions easing curve applied.
+    /// Starts at 0. and ends at 1., but may leave this range in between for some easing curves.
+    /// Transitions without a duration are always considered complete.
     pub(crate) fn get_progress_ratio(&self) -> f32 {
-        self.progressed_duration.as_secs_f32() / self.total_duration.as_secs_f32()
+        if self.total_duration.is_zero() {
+            return 1.;
+        }
+        self.easing
+            .apply(self.progressed_duration.as_secs_f32() / self.total_duration.as_secs_f32())
     }
 }
 
+/// Returns the average of two colors (all four components are averaged). color1 is weighted by (1-ratio), while color2 is weighted by ratio.
+pub(crate) fn average_color(color1: Color, color2: Color, ratio: f32) -> Color {
+    Color::new(
+        color1.r * (1. - ratio) + color2.r * ratio,
+        color1.g * (1. - ratio) + color2.g * ratio,
+        color1.b * (1. - ratio) + color2.b * ratio,
+        color1.a * (1. - ratio) + color2.a * ratio,
+    )
+}
+
 /// Returns the average of two rectangles (all four values are averaged). rect1 is weighted by (1-ratio), while rect2 is weighted by ratio.
 /// Thus, ratio=0 returns rect1 and ratio=1 returns rect2. The progression between the two is linear and continuous.
 pub(crate) fn average_rect(rect1: &Rect, rect2: &Rect, ratio: f32) -> Rect {
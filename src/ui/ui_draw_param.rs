@@ -1,4 +1,9 @@
-use ggez::graphics::{DrawParam, Rect};
+use ggez::{
+    glam::{Affine2, Vec2},
+    graphics::{Canvas, Color, DrawParam, Rect},
+};
+
+use super::ui_transform::transform_rect;
 
 /// An extension of the [ggez::graphics::DrawParam] struct specifically for UiElements.
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
@@ -7,6 +12,12 @@ pub struct UiDrawParam {
     pub target: Rect,
     /// Wether or not the element should listen to the mouse position and possible change its visuals or display a tooltip when hovered over.
     pub mouse_listen: bool,
+    /// The rectangle (in screen coordinates) everything drawn with this param is clipped to, if any.
+    /// Nothing outside of this rectangle will be drawn and elements will not react to the mouse outside of it.
+    pub clip: Option<Rect>,
+    /// The transformation from the coordinates of the target area to screen coordinates, resulting from the [super::UiTransform]s of all ancestors.
+    /// The projection of the canvas is set accordingly while drawing, so contents can simply draw to their target.
+    pub transform: Affine2,
     /// A basic draw param struct to manage things like z-level, color and src-rect.
     /// Setting dest_rect may yield unexpected behaviour, as it will likely be overwritten by target when drawing.
     pub param: DrawParam,
@@ -18,6 +29,8 @@ impl UiDrawParam {
         Self {
             target: Rect::default(),
             mouse_listen: true,
+            clip: None,
+            transform: Affine2::IDENTITY,
             param: DrawParam::new(),
         }
     }
@@ -35,6 +48,71 @@ impl UiDrawParam {
         }
     }
 
+    /// Returns a new [UiDrawParam] that is clipped to the intersection of the specified rectangle and any clip rectangle already present.
+    /// The rectangle is given in the same coordinates as the target area. If those are rotated, the clip rectangle is enlarged to stay axis-aligned on the screen.
+    pub fn clip(self, clip: Rect) -> Self {
+        let clip = transform_rect(self.transform, clip);
+        Self {
+            clip: Some(match self.clip {
+                None => clip,
+                Some(old_clip) => intersect_rects(&old_clip, &clip),
+            }),
+            ..self
+        }
+    }
+
+    /// Returns a new [UiDrawParam] with any clipping removed.
+    pub fn no_clip(self) -> Self {
+        Self { clip: None, ..self }
+    }
+
+    /// Returns a new [UiDrawParam] whose target area coordinates are additionally transformed by the passed transformation before being mapped to the screen.
+    pub fn transformed(self, transform: Affine2) -> Self {
+        Self {
+            transform: self.transform * transform,
+            ..self
+        }
+    }
+
+    /// Returns a new [UiDrawParam] with any transformation removed, so the target area is given in screen coordinates.
+    /// Only use this if the projection of the canvas does not include any transformation, either.
+    pub fn no_transform(self) -> Self {
+        Self {
+            transform: Affine2::IDENTITY,
+            ..self
+        }
+    }
+
+    /// Maps the passed point from screen coordinates (e.g. the mouse position) to the coordinates of the target area.
+    pub fn to_local(&self, point: impl Into<Vec2>) -> Vec2 {
+        self.transform.inverse().transform_point2(point.into())
+    }
+
+    /// Returns wether the passed point (in screen coordinates) is within the clip rectangle of this param. Always returns true if there is no clip rectangle.
+    pub fn clip_contains(&self, point: impl Into<ggez::mint::Point2<f32>>) -> bool {
+        self.clip.is_none_or(|clip| clip.contains(point))
+    }
+
+    /// Runs the passed drawing function with the scissor rectangle of the canvas restricted to the clip rectangle of this param and restores the old scissor rectangle afterwards.
+    /// If the clip rectangle has no visible area, the drawing function is not run at all.
+    /// Use this in [super::UiContent::draw_content] of your own contents if they draw to the canvas directly.
+    pub fn draw_clipped(&self, canvas: &mut Canvas, draw: impl FnOnce(&mut Canvas)) {
+        match self.clip {
+            None => draw(canvas),
+            Some(clip) => {
+                let previous = canvas.scissor_rect();
+                let clip = intersect_rects(&previous, &clip);
+                // the scissor rectangle is in (rounded) pixel coordinates
+                if clip.w >= 1. && clip.h >= 1. && canvas.set_scissor_rect(clip).is_ok() {
+                    draw(canvas);
+                    if canvas.set_scissor_rect(previous).is_err() {
+                        canvas.set_default_scissor_rect();
+                    }
+                }
+            }
+        }
+    }
+
     /// Returns a new [UiDrawParam] with only the z value of the contained param set to the specified value.
     pub fn z_level(self, z_level: i32) -> Self {
         Self {
@@ -43,6 +121,26 @@ impl UiDrawParam {
         }
     }
 
+    /// Returns a new [UiDrawParam] with the color of the contained param multiplied (component-wise, including alpha) by the specified color.
+    pub fn tint(self, tint: Color) -> Self {
+        Self {
+            param: self.param.color(self.tinted(tint)),
+            ..self
+        }
+    }
+
+    /// Returns the passed color multiplied (component-wise, including alpha) by the color of the contained param.
+    /// Use this in [super::UiContent::draw_content] of your own contents if they draw with colors other than the one of the param, so they respect the opacity and tint of their element.
+    pub fn tinted(&self, color: Color) -> Color {
+        let own = self.param.color;
+        Color::new(
+            own.r * color.r,
+            own.g * color.g,
+            own.b * color.b,
+            own.a * color.a,
+        )
+    }
+
     /// Returns a new [UiDrawParam] with the entire DrawParam replaced by the specified value.
     pub fn param(self, param: DrawParam) -> Self {
         Self { param, ..self }
@@ -54,6 +152,8 @@ impl From<DrawParam> for UiDrawParam {
         Self {
             target: Rect::default(),
             mouse_listen: true,
+            clip: None,
+            transform: Affine2::IDENTITY,
             param: value,
         }
     }
@@ -64,3 +164,15 @@ impl From<UiDrawParam> for DrawParam {
         value.param
     }
 }
+
+/// Returns the intersection of two rectangles, or a rectangle of size zero if they do not overlap.
+pub(crate) fn intersect_rects(rect1: &Rect, rect2: &Rect) -> Rect {
+    let x = rect1.x.max(rect2.x);
+    let y = rect1.y.max(rect2.y);
+    Rect::new(
+        x,
+        y,
+        ((rect1.x + rect1.w).min(rect2.x + rect2.w) - x).max(0.),
+        ((rect1.y + rect1.h).min(rect2.y + rect2.h) - y).max(0.),
+    )
+}
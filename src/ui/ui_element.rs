@@ -1,8 +1,13 @@
-use std::hash::Hash;
+use std::{any::Any, collections::HashSet, hash::Hash};
 
 use super::*;
 
-use ggez::{graphics::Canvas, Context};
+use ggez::{
+    glam::Affine2,
+    graphics::{Canvas, Rect},
+    input::keyboard::KeyInput,
+    Context,
+};
 
 /// A trait that marks any struct that can be the content of a UI element. Should not be used directly, only when wrapped in such an element.
 /// ### Basic elements
@@ -33,6 +38,89 @@ pub trait UiContent<T: Copy + Eq + Hash> {
     /// Do not call otherwise.
     fn draw_content(&mut self, ctx: &mut Context, canvas: &mut Canvas, param: UiDrawParam);
 
+    /// Returns a short name of the type of this content, e.g. for debugging or layout snapshots.
+    /// Default implementation returns the name of the type without its module path and generic parameters.
+    fn content_name(&self) -> &'static str {
+        let name = std::any::type_name::<Self>();
+        let name = name.split('<').next().unwrap_or(name);
+        name.rsplit("::").next().unwrap_or(name)
+    }
+
+    /// Receives the input state of the current frame during [UiElement::update_with_input], before the messages of this content are collected.
+    /// `target` is the area the content was last drawn to and `transform` the transformation from the coordinates of that area to screen coordinates.
+    /// `hovered` contains wether the mouse cursor is above the element (or one of its successors) without being covered by another element.
+    /// React to mouse and keyboard input here instead of reading the [Context] while drawing, so the content can be driven by a [UiHarness].
+    /// Default implementation does nothing.
+    fn update_input(
+        &mut self,
+        _input: &InputState,
+        _target: Rect,
+        _transform: Affine2,
+        _hovered: bool,
+    ) {
+    }
+
+    /// Receives a mouse wheel event (see [ggez::event::EventHandler::mouse_wheel_event]) that was not consumed by any child of this element.
+    /// `input` contains the input state at the time of the event.
+    /// Returns true if the content reacted to (and thus consumed) the event.
+    /// Default implementation ignores all events.
+    fn mouse_wheel_event(&mut self, _input: &InputState, _x: f32, _y: f32) -> bool {
+        false
+    }
+
+    /// Receives a text input event (see [ggez::event::EventHandler::text_input_event]) that was not consumed by any child of this element.
+    /// Returns true if the content reacted to (and thus consumed) the event.
+    /// Default implementation ignores all events.
+    fn text_input_event(&mut self, _character: char) -> bool {
+        false
+    }
+
+    /// Receives a key press event (see [ggez::event::EventHandler::key_down_event]) that was not consumed by any child of this element.
+    /// Returns true if the content reacted to (and thus consumed) the event.
+    /// Default implementation ignores all events.
+    fn key_down_event(&mut self, _input: KeyInput) -> bool {
+        false
+    }
+
+    /// Called when the element containing this content holds the keyboard focus and is triggered by pressing Enter or Space.
+    /// Default implementation does nothing.
+    fn focus_triggered(&mut self) {}
+
+    /// Adds all messages this content wants to send since the last call of this function to the passed set.
+    /// The ID of the element containing this content is passed in to identify the sender. Only called for elements with an ID other than 0.
+    /// Default implementation sends no messages.
+    fn collect_messages(&mut self, _id: u32, _messages: &mut HashSet<UiMessage<T>>) {}
+
+    /// Returns wether this content currently captures all keyboard input (e.g. a text field that is being typed into).
+    /// While any content captures the keyboard, no element will be triggered by its trigger keys.
+    /// Default implementation returns false.
+    fn captures_keyboard(&self) -> bool {
+        false
+    }
+
+    /// Returns the text displayed by this content, if it is editable by the user.
+    /// Default implementation returns None.
+    fn text_value(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns the current value of this content, if it is a widget the user can change (see [UiElement::get_value]).
+    /// Contents returning a value should also send a [UiMessage::ValueChanged] from [UiContent::collect_messages] whenever the user changes it.
+    /// Default implementation returns None.
+    fn value(&self) -> Option<UiValue> {
+        None
+    }
+
+    /// Applies the font and text scale of a [Style] of the current [Theme] to this content.
+    /// Contents displaying text should overwrite this and adapt the passed layout of their element to their new size if neccessary.
+    /// Default implementation ignores the style.
+    fn apply_style(&mut self, _ctx: &Context, _style: &Style, _layout: &mut Layout) {}
+
+    /// Measures this content with the passed [Measure] and adapts the passed layout of its element to the measured size, just as [UiContent::to_element_builder] would.
+    /// Used to lay out elements without a [Context], see [UiElement::measure].
+    /// Default implementation does nothing, as most contents do not need a context to determine their size.
+    fn measure(&mut self, _measure: &dyn Measure, _layout: &mut Layout) {}
+
     /// Returns a bool value. Returning true indicates to any container this element is a child of that this element wishes to be removed from the container (and discarded).
     fn expired(&self) -> bool {
         false
@@ -51,6 +139,20 @@ pub trait UiContent<T: Copy + Eq + Hash> {
     fn container_mut(&mut self) -> Option<&mut dyn UiContainer<T>> {
         None
     }
+
+    /// Returns an immutable reference to Self (cast to [Any]), allowing [UiElement::content_as] to cast it back to its actual type.
+    /// Default implementation returns None, so the content cannot be accessed by its type.
+    /// Overwrite this function (returning `Some(self)`) for your custom contents to make them accessible!
+    fn as_any(&self) -> Option<&dyn Any> {
+        None
+    }
+
+    /// Returns a mutable reference to Self (cast to [Any]), allowing [UiElement::content_as_mut] to cast it back to its actual type.
+    /// Default implementation returns None, so the content cannot be accessed by its type.
+    /// Overwrite this function (returning `Some(self)`) for your custom contents to make them accessible!
+    fn as_any_mut(&mut self) -> Option<&mut dyn Any> {
+        None
+    }
 }
 
 /// This trait marks a special type of UiContent that contains other UiElements.
@@ -68,6 +170,20 @@ pub trait UiContainer<T: Copy + Eq + Hash>: UiContent<T> {
         (0., f32::INFINITY)
     }
 
+    /// Returns wether the children of this container are drawn in reverse order, i.e. the first child is drawn on top of all others.
+    /// Used to find the topmost element below the mouse cursor.
+    /// Default implementation returns false.
+    fn reverse_draw_order(&self) -> bool {
+        false
+    }
+
+    /// Returns the rectangles the children of this container are drawn to when the content is drawn to the passed target, in the order of [UiContainer::get_children].
+    /// Used to calculate layouts without drawing, see [UiElement::compute_layout].
+    /// Default implementation passes the entire target to every child.
+    fn get_child_targets(&self, target: Rect) -> Vec<Rect> {
+        vec![target; self.get_children().len()]
+    }
+
     /// Returns access to this elements children, if there are any. Returns None if this is a leaf node.
     fn get_children(&self) -> &[UiElement<T>];
 
@@ -82,4 +198,10 @@ pub trait UiContainer<T: Copy + Eq + Hash>: UiContent<T> {
 
     /// Removes all elements from this container whose ids match.
     fn remove_id(&mut self, id: u32);
+
+    /// Removes the first element from this container whose id matches and returns it.
+    /// Default implementation returns None and does not remove anything.
+    fn take_id(&mut self, _id: u32) -> Option<UiElement<T>> {
+        None
+    }
 }
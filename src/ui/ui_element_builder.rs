@@ -1,9 +1,9 @@
-use ggez::{audio::Source, winit::event::VirtualKeyCode};
+use ggez::{audio::Source, event::Button, winit::event::VirtualKeyCode};
 
 use crate::ui::{UiContent, UiElement};
 use std::hash::Hash;
 
-use super::Layout;
+use super::{Animation, Layout, Theme, UiTransform};
 
 /// A builder struct for UiElements. Allows changing of all relevant fields of the built element, and contains shorthand function for changing the components of the elements layout.
 /// Also contains shorthand functions for some very frequently used combination of layout settings.
@@ -22,6 +22,12 @@ impl<T: Copy + Eq + Hash> UiElementBuilder<T> {
         }
     }
 
+    /// Sets the ID of the element. Use ID 0 iff you do not want this element to send any messages by itself.
+    pub fn with_id(mut self, id: u32) -> Self {
+        self.element.id = id;
+        self
+    }
+
     /// If the elements content is a container, the passed element is added to it.
     /// Otherwise, the passed element is discarded.
     pub fn with_child(mut self, element: UiElement<T>) -> Self {
@@ -45,12 +51,68 @@ impl<T: Copy + Eq + Hash> UiElementBuilder<T> {
     /// Sets the elements visuals.
     pub fn with_visuals(mut self, visuals: super::Visuals) -> Self {
         self.element.visuals = visuals;
+        if let Some(unthemed) = &mut self.element.unthemed {
+            unthemed.visuals = visuals;
+        }
         self
     }
 
     /// Sets the elements hover_visuals. Pass in None to delete any existing hover_visuals.
     pub fn with_hover_visuals(mut self, hover_visuals: impl Into<Option<super::Visuals>>) -> Self {
         self.element.hover_visuals = hover_visuals.into();
+        if let Some(unthemed) = &mut self.element.unthemed {
+            unthemed.hover_visuals = self.element.hover_visuals;
+        }
+        self
+    }
+
+    /// Sets the elements focus_visuals, displayed while the element holds the keyboard focus. Pass in None to delete any existing focus_visuals.
+    pub fn with_focus_visuals(mut self, focus_visuals: impl Into<Option<super::Visuals>>) -> Self {
+        self.element.focus_visuals = focus_visuals.into();
+        if let Some(unthemed) = &mut self.element.unthemed {
+            unthemed.focus_visuals = self.element.focus_visuals;
+        }
+        self
+    }
+
+    /// Sets the elements opacity. An opacity of 0. makes the element and all its children invisible, 1. (the default) fully visible.
+    /// The opacity is multiplied with the opacity of the parent element.
+    pub fn with_opacity(mut self, opacity: f32) -> Self {
+        self.element.opacity = opacity;
+        self
+    }
+
+    /// Sets the elements tint. The colors of the element and all its children are multiplied with this color (and the tint of the parent element).
+    /// Default is white, leaving all colors unchanged.
+    pub fn with_tint(mut self, tint: ggez::graphics::Color) -> Self {
+        self.element.tint = tint;
+        self
+    }
+
+    /// Sets the elements transform, scaling, rotating and moving the element and all its children when drawn.
+    /// The transform does not change the layout of the element or its siblings, but is respected when determining wether the mouse hovers above the element.
+    pub fn with_transform(mut self, transform: UiTransform) -> Self {
+        self.element.transform = transform;
+        self
+    }
+
+    /// Sets an animation that plays on this element independently of its transitions, e.g. to make it pulse or bob up and down.
+    pub fn with_animation(mut self, animation: Animation<T>) -> Self {
+        self.element.animation = Some(animation);
+        self
+    }
+
+    /// Adds a style class to this element. When a [Theme] is applied, the style with this name is applied to the element.
+    /// Multiple classes can be added, later classes overwrite values set by earlier ones.
+    pub fn with_class(mut self, class: impl Into<String>) -> Self {
+        self.element.classes.push(class.into());
+        self
+    }
+
+    /// Applies the passed theme to the element built so far and all its children, according to their style classes. See [UiElement::apply_theme].
+    /// Values set after this call overwrite values of the theme, and are kept when another theme is applied later.
+    pub fn with_theme(mut self, ctx: &ggez::Context, theme: &Theme) -> Self {
+        self.element.apply_theme(ctx, theme);
         self
     }
 
@@ -78,6 +140,7 @@ impl<T: Copy + Eq + Hash> UiElementBuilder<T> {
     /// Sets the elements message handler.
     /// The message handler lambda receives each frame a hash set consisting of all internal and external messages received by this element.
     /// It also receives a function pointer. Calling this pointer with a transition pushes that transition to this elements transition queue.
+    /// The queue can also be cleared first to replace any transitions still running, e.g. when the user clicks repeatedly.
     /// Lastly, it receives the current layout of the element. This allows any transitions to re-use that layout and only change the variables the transition wants to change.
     pub fn with_message_handler(
         mut self,
@@ -86,6 +149,23 @@ impl<T: Copy + Eq + Hash> UiElementBuilder<T> {
                 Layout,
                 &mut std::collections::VecDeque<super::Transition<T>>,
             ) + 'static,
+    ) -> Self {
+        self.element.message_handler = Box::new(move |messages, context| {
+            handler(messages, context.layout(), context.transitions_mut())
+        });
+        self
+    }
+
+    /// Sets the elements message handler to a handler that can change the element directly and keep its own state.
+    /// The message handler lambda receives each frame a hash set consisting of all internal and external messages received by this element.
+    /// It also receives a [super::HandlerContext], giving it mutable access to the content, layout, visuals and tooltip of the element, its transition queue and the ability to emit new messages.
+    /// Replaces any handler set via [UiElementBuilder::with_message_handler].
+    pub fn with_message_handler_mut(
+        mut self,
+        handler: impl FnMut(
+                &std::collections::HashSet<crate::ui::UiMessage<T>>,
+                &mut super::HandlerContext<T>,
+            ) + 'static,
     ) -> Self {
         self.element.message_handler = Box::new(handler);
         self
@@ -94,6 +174,9 @@ impl<T: Copy + Eq + Hash> UiElementBuilder<T> {
     /// Sets the elements entire layout.
     pub fn with_layout(mut self, layout: super::Layout) -> Self {
         self.element.layout = layout;
+        if let Some(unthemed) = &mut self.element.unthemed {
+            unthemed.padding = layout.padding;
+        }
         self
     }
 
@@ -141,9 +224,69 @@ impl<T: Copy + Eq + Hash> UiElementBuilder<T> {
         self
     }
 
+    /// Sets wether this element can receive the keyboard focus. Only elements with an ID other than 0 can be focused.
+    /// The focus can be moved with Tab, Shift + Tab and the arrow keys or by clicking an element. Pressing Enter or Space triggers the focused element.
+    pub fn with_focusable(mut self, focusable: bool) -> Self {
+        self.element.focusable = focusable;
+        self
+    }
+
+    /// Attaches a gamepad button to this element. Pressing this button will send the same trigger event as clicking the element.
+    /// Gamepad events need to be passed to the UI via [super::UiElement::gamepad_button_down_event].
+    /// Back and cancel buttons do not need to be bound to the east button (B on most controllers), see [UiElementBuilder::with_back].
+    pub fn with_gamepad_button(mut self, button: Button) -> Self {
+        self.element.gamepad_buttons.push(Some(button));
+        self
+    }
+
+    /// Sets wether this element is a back or cancel button. Pressing the east button of a gamepad (B on most controllers) triggers all back buttons, just like clicking them.
+    pub fn with_back(mut self, back: bool) -> Self {
+        self.element.back = back;
+        self
+    }
+
+    /// Makes this element draggable, carrying the passed payload. Pass None to make the element no longer draggable.
+    /// While being dragged, the element is drawn following the mouse cursor (its space in the layout stays reserved).
+    /// Dropping it onto an element accepting the payload (see [UiElementBuilder::with_drop_target]) sends a [super::UiMessage::DragDropped].
+    /// Only elements with an ID other than 0 can be dragged.
+    pub fn with_drag_payload(mut self, payload: impl Into<Option<T>>) -> Self {
+        self.element.drag_payload = payload.into();
+        self
+    }
+
+    /// Makes this element a drop target for dragged elements whose payload is accepted by the passed predicate.
+    /// Only elements with an ID other than 0 can be drop targets.
+    pub fn with_drop_target(mut self, accepts: impl Fn(&T) -> bool + 'static) -> Self {
+        self.element.drop_filter = Some(Box::new(accepts));
+        self
+    }
+
+    /// Sets the elements drop_visuals, displayed while an element with a payload accepted by this element is dragged above it. Pass in None to delete any existing drop_visuals.
+    pub fn with_drop_visuals(mut self, drop_visuals: impl Into<Option<super::Visuals>>) -> Self {
+        self.element.drop_visuals = drop_visuals.into();
+        self
+    }
+
+    /// Sets wether this element lets the mouse pass through to the elements below it, e.g. for decorative elements drawn on top of buttons.
+    /// Elements that pass through the mouse do not send mouse-related messages, show hover visuals or tooltips, but their children may still do so.
+    pub fn with_pass_through(mut self, pass_through: bool) -> Self {
+        self.element.pass_through = pass_through;
+        self
+    }
+
+    /// Sets wether the content of this element (and thus all of its children) is clipped to the outer bounds of this element.
+    /// Clipped children are not drawn and do not react to the mouse outside of these bounds, even while moving out of them during a transition.
+    pub fn with_clip_children(mut self, clip_children: bool) -> Self {
+        self.element.clip_children = clip_children;
+        self
+    }
+
     /// Sets only the padding of the elements layout.
     pub fn with_padding(mut self, padding: (f32, f32, f32, f32)) -> Self {
         self.element.layout.padding = padding;
+        if let Some(unthemed) = &mut self.element.unthemed {
+            unthemed.padding = padding;
+        }
         self
     }
 
@@ -0,0 +1,117 @@
+use ggez::{
+    glam::{Affine2, Mat4, Vec2, Vec4},
+    graphics::Rect,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// A struct that describes how an element (and all its children) is scaled, rotated and moved when drawn.
+/// Transforms do not influence the layout, neither of the element itself nor of its siblings, but are respected when checking wether the mouse hovers above an element.
+pub struct UiTransform {
+    /// The horizontal and vertical scale factors.
+    pub scale: Vec2,
+    /// The clockwise rotation in radians.
+    pub rotation: f32,
+    /// The movement in pixels, applied after scaling and rotating.
+    pub translation: Vec2,
+    /// The point scaling and rotation happen around, relative to the outer bounds of the element. (0,0) is the top left corner, (1,1) the bottom right corner.
+    pub anchor: Vec2,
+}
+
+impl Default for UiTransform {
+    fn default() -> Self {
+        Self {
+            scale: Vec2::ONE,
+            rotation: 0.,
+            translation: Vec2::ZERO,
+            anchor: Vec2::new(0.5, 0.5),
+        }
+    }
+}
+
+impl UiTransform {
+    /// Returns a new UiTransform that does not change the element, anchored at the center of the element.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns this transform with the specified uniform scale factor.
+    pub fn with_scale(self, scale: f32) -> Self {
+        Self {
+            scale: Vec2::splat(scale),
+            ..self
+        }
+    }
+
+    /// Returns this transform with the specified clockwise rotation in radians.
+    pub fn with_rotation(self, rotation: f32) -> Self {
+        Self { rotation, ..self }
+    }
+
+    /// Returns this transform with the specified movement in pixels.
+    pub fn with_translation(self, x: f32, y: f32) -> Self {
+        Self {
+            translation: Vec2::new(x, y),
+            ..self
+        }
+    }
+
+    /// Returns this transform with the specified anchor, relative to the outer bounds of the element.
+    pub fn with_anchor(self, x: f32, y: f32) -> Self {
+        Self {
+            anchor: Vec2::new(x, y),
+            ..self
+        }
+    }
+
+    /// Returns wether this transform leaves the element unchanged.
+    pub fn is_identity(&self) -> bool {
+        self.scale == Vec2::ONE && self.rotation == 0. && self.translation == Vec2::ZERO
+    }
+
+    /// Returns the affine transformation this transform applies to an element drawn to the passed outer rectangle.
+    pub fn to_affine(&self, outer: Rect) -> Affine2 {
+        let anchor = Vec2::new(
+            outer.x + self.anchor.x * outer.w,
+            outer.y + self.anchor.y * outer.h,
+        );
+        Affine2::from_translation(anchor + self.translation)
+            * Affine2::from_scale_angle_translation(self.scale, self.rotation, Vec2::ZERO)
+            * Affine2::from_translation(-anchor)
+    }
+
+    /// Returns a transform that is an average of this and the passed transform. Self is weighted by (1-ratio), while other is weighted by ratio.
+    pub fn average(&self, other: Self, ratio: f32) -> Self {
+        Self {
+            scale: self.scale.lerp(other.scale, ratio),
+            rotation: self.rotation * (1. - ratio) + other.rotation * ratio,
+            translation: self.translation.lerp(other.translation, ratio),
+            anchor: self.anchor.lerp(other.anchor, ratio),
+        }
+    }
+}
+
+/// Converts a two-dimensional affine transformation to a homogenous 4x4 matrix, as used for canvas projections.
+pub(crate) fn affine_to_mat4(affine: Affine2) -> Mat4 {
+    Mat4::from_cols(
+        affine.matrix2.x_axis.extend(0.).extend(0.),
+        affine.matrix2.y_axis.extend(0.).extend(0.),
+        Vec4::Z,
+        affine.translation.extend(0.).extend(1.),
+    )
+}
+
+/// Returns the smallest axis-aligned rectangle containing the passed rectangle after applying the passed transformation.
+pub(crate) fn transform_rect(affine: Affine2, rect: Rect) -> Rect {
+    let corners = [
+        Vec2::new(rect.x, rect.y),
+        Vec2::new(rect.x + rect.w, rect.y),
+        Vec2::new(rect.x, rect.y + rect.h),
+        Vec2::new(rect.x + rect.w, rect.y + rect.h),
+    ]
+    .map(|corner| affine.transform_point2(corner));
+    let min = corners.iter().fold(Vec2::INFINITY, |acc, c| acc.min(*c));
+    let max = corners
+        .iter()
+        .fold(Vec2::NEG_INFINITY, |acc, c| acc.max(*c));
+    Rect::new(min.x, min.y, max.x - min.x, max.y - min.y)
+}
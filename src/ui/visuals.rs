@@ -59,13 +59,15 @@ impl Visuals {
 
     /// Draws the background to the canvas, filling the rectangle target.
     pub(crate) fn draw(&self, ctx: &Context, canvas: &mut Canvas, param: super::UiDrawParam) {
-        canvas.draw(
-            &Mesh::from_data(
-                ctx,
-                self.create_mesh(param.target).unwrap_or_default().build(),
-            ),
-            param.param,
-        );
+        param.draw_clipped(canvas, |canvas| {
+            canvas.draw(
+                &Mesh::from_data(
+                    ctx,
+                    self.create_mesh(param.target).unwrap_or_default().build(),
+                ),
+                param.param,
+            );
+        });
     }
 
     /// Creates a mesh that can be used to draw this visual based on its attributes.
@@ -0,0 +1,82 @@
+//! Tests of the easing curves transitions and animations can use.
+
+use mooeye::ui::Easing;
+
+/// Asserts that the two passed values differ by less than 0.001.
+fn assert_close(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < 1e-3,
+        "{} is not close to {}",
+        actual,
+        expected
+    );
+}
+
+#[test]
+fn start_and_end() {
+    let curves = [
+        Easing::Linear,
+        Easing::QuadIn,
+        Easing::QuadOut,
+        Easing::QuadInOut,
+        Easing::CubicIn,
+        Easing::CubicOut,
+        Easing::CubicInOut,
+        Easing::BackIn,
+        Easing::BackOut,
+        Easing::BackInOut,
+        Easing::ElasticIn,
+        Easing::ElasticOut,
+        Easing::BounceIn,
+        Easing::BounceOut,
+        Easing::Steps(0),
+        Easing::Steps(4),
+        Easing::CubicBezier(0.25, 0.1, 0.25, 1.),
+        Easing::CubicBezier(0.68, -0.6, 0.32, 1.6),
+        Easing::Custom(|t| t.sqrt()),
+    ];
+
+    for curve in curves {
+        assert_close(curve.apply(0.), 0.);
+        assert_close(curve.apply(1.), 1.);
+        // progress outside of [0, 1] is clamped
+        assert_close(curve.apply(-1.), 0.);
+        assert_close(curve.apply(2.), 1.);
+    }
+}
+
+#[test]
+fn spot_checks() {
+    assert_close(Easing::QuadIn.apply(0.5), 0.25);
+    assert_close(Easing::QuadInOut.apply(0.5), 0.5);
+    assert_close(Easing::CubicOut.apply(0.5), 0.875);
+    assert_close(Easing::Steps(4).apply(0.3), 0.25);
+    assert_close(Easing::BounceOut.apply(1. / 2.75), 1.);
+}
+
+#[test]
+fn cubic_bezier() {
+    // control points on the diagonal result in a linear curve
+    let linear = Easing::CubicBezier(0.25, 0.25, 0.75, 0.75);
+    for t in [0.1, 0.3, 0.5, 0.9] {
+        assert_close(linear.apply(t), t);
+    }
+
+    // the CSS curve 'ease'
+    let ease = Easing::CubicBezier(0.25, 0.1, 0.25, 1.);
+    assert_close(ease.apply(0.5), 0.8024);
+    assert_close(ease.apply(0.25), 0.4094);
+}
+
+#[test]
+fn overshoot() {
+    // back curves move below the start and beyond the target
+    assert!(Easing::BackIn.apply(0.2) < 0.);
+    assert!(Easing::BackOut.apply(0.8) > 1.);
+    assert!(Easing::BackInOut.apply(0.1) < 0.);
+    assert!(Easing::BackInOut.apply(0.9) > 1.);
+
+    // elastic curves oscillate around start and target
+    assert_close(Easing::ElasticOut.apply(0.1), 1.25);
+    assert_close(Easing::ElasticIn.apply(0.9), -0.25);
+}
@@ -0,0 +1,542 @@
+//! Headless input tests. These simulate mouse and keyboard input with a [ui::UiHarness] and check the messages sent and the transitions started by message handlers.
+
+use std::{collections::HashSet, time::Duration};
+
+use ggez::{
+    graphics::{Rect, Text},
+    winit::event::VirtualKeyCode,
+};
+use mooeye::ui::{self, UiContainer, UiMessage};
+
+/// Creates a focusable text button without a context.
+fn button(id: u32, text: &str) -> ui::UiElementBuilder<()> {
+    ui::UiElementBuilder::new(id, Text::new(text)).with_focusable(true)
+}
+
+/// Creates a harness running a horizontal box containing the passed buttons within an 800x600 screen.
+fn harness(buttons: Vec<ui::UiElement<()>>) -> ui::UiHarness<()> {
+    let mut hbox = ui::containers::HorizontalBox::new();
+    for button in buttons {
+        hbox.add(button);
+    }
+    let mut gui = ui::UiElementBuilder::new(0, hbox).as_shrink().build();
+    gui.measure(&ui::MonospaceMeasure::default());
+    ui::UiHarness::new(gui, Rect::new(0., 0., 800., 600.))
+}
+
+#[test]
+fn click() {
+    let mut harness = harness(vec![
+        button(1, "First").build(),
+        button(2, "Second").build(),
+    ]);
+
+    let messages = harness.click(2);
+    assert!(messages.contains(&UiMessage::HoverStart(2)));
+    assert!(messages.contains(&UiMessage::Clicked(2)));
+    assert!(messages.contains(&UiMessage::Triggered(2)));
+    assert!(messages.contains(&UiMessage::MouseReleased(2)));
+    assert!(!messages.contains(&UiMessage::Clicked(1)));
+    assert_eq!(harness.root().get_focus(), Some(2));
+
+    // a second click within the double click time
+    assert!(harness.click(2).contains(&UiMessage::DoubleClicked(2)));
+
+    // leaving the element
+    harness.move_mouse((700., 500.));
+    assert!(harness.frame().contains(&UiMessage::HoverEnd(2)));
+    assert!(harness.frame().is_empty());
+}
+
+#[test]
+fn keyboard() {
+    let mut harness = harness(vec![
+        button(1, "First")
+            .with_trigger_key(VirtualKeyCode::A)
+            .build(),
+        button(2, "Second").build(),
+    ]);
+
+    let messages = harness.tap_key(VirtualKeyCode::A);
+    assert!(messages.contains(&UiMessage::PressedKey(1)));
+    assert!(messages.contains(&UiMessage::Triggered(1)));
+
+    // holding a key only triggers once
+    harness.press_key(VirtualKeyCode::A);
+    assert!(harness.frame().contains(&UiMessage::PressedKey(1)));
+    assert!(harness.frame().is_empty());
+    harness.release_key(VirtualKeyCode::A);
+
+    // moving the focus and triggering the focused element
+    harness.tap_key(VirtualKeyCode::Tab);
+    harness.tap_key(VirtualKeyCode::Tab);
+    assert_eq!(harness.root().get_focus(), Some(2));
+    assert!(harness
+        .tap_key(VirtualKeyCode::Return)
+        .contains(&UiMessage::Triggered(2)));
+}
+
+#[test]
+fn gamepad() {
+    let mut harness = harness(vec![
+        button(1, "First").build(),
+        button(2, "Second").build(),
+        button(3, "Back").with_back(true).build(),
+        button(4, "Map")
+            .with_gamepad_button(ggez::event::Button::West)
+            .build(),
+    ]);
+    harness.root_mut().set_focus(1);
+
+    // the D-pad moves the focus
+    assert!(harness
+        .root_mut()
+        .gamepad_button_down_event(ggez::event::Button::DPadRight));
+    assert_eq!(harness.root().get_focus(), Some(2));
+
+    // the stick moves the focus once per push
+    let root = harness.root_mut();
+    assert!(root.gamepad_axis_event(ggez::event::Axis::LeftStickX, 0.8));
+    assert!(!root.gamepad_axis_event(ggez::event::Axis::LeftStickX, 1.));
+    assert_eq!(root.get_focus(), Some(3));
+    assert!(!root.gamepad_axis_event(ggez::event::Axis::LeftStickX, 0.));
+    assert!(root.gamepad_axis_event(ggez::event::Axis::LeftStickX, -0.8));
+    assert_eq!(root.get_focus(), Some(2));
+
+    // south triggers the focused element
+    assert!(harness
+        .root_mut()
+        .gamepad_button_down_event(ggez::event::Button::South));
+    let messages = harness.frame();
+    assert!(messages.contains(&UiMessage::PressedGamepadButton(2)));
+    assert!(messages.contains(&UiMessage::Triggered(2)));
+    assert!(!messages.contains(&UiMessage::Triggered(1)));
+
+    // east triggers back buttons without being bound to them, regardless of the focus
+    assert!(harness
+        .root_mut()
+        .gamepad_button_down_event(ggez::event::Button::East));
+    let messages = harness.frame();
+    assert!(messages.contains(&UiMessage::PressedGamepadButton(3)));
+    assert!(messages.contains(&UiMessage::Triggered(3)));
+    assert_eq!(harness.root().get_focus(), Some(2));
+
+    // bound buttons trigger their element regardless of the focus
+    assert!(harness
+        .root_mut()
+        .gamepad_button_down_event(ggez::event::Button::West));
+    assert!(harness.frame().contains(&UiMessage::Triggered(4)));
+
+    // unbound buttons have no effect
+    assert!(!harness
+        .root_mut()
+        .gamepad_button_down_event(ggez::event::Button::North));
+    assert!(harness.frame().is_empty());
+}
+
+#[test]
+fn find_and_change_content() {
+    let mut harness = harness(vec![
+        button(1, "Short").build(),
+        button(2, "Twin").build(),
+        button(2, "Twin").build(),
+    ]);
+
+    let root = harness.root();
+    assert_eq!(root.find(1).map(|element| element.get_id()), Some(1));
+    assert!(root.find(3).is_none());
+    assert_eq!(root.find_all(2).len(), 2);
+    assert!(root.find(1).unwrap().content_as::<Text>().is_some());
+    assert!(root
+        .find(0)
+        .unwrap()
+        .content_as::<ui::containers::HorizontalBox<()>>()
+        .is_none());
+
+    // changed contents are measured again before they are drawn
+    *harness
+        .root_mut()
+        .find_mut(1)
+        .and_then(|element| element.content_as_mut::<Text>())
+        .unwrap() = Text::new("Much longer");
+    harness.frame();
+    assert_eq!(harness.layout().get(1).unwrap().inner.w, 88.);
+}
+
+#[test]
+fn transition() {
+    let moving = button(1, "Move")
+        .with_message_handler(|messages, layout, transitions| {
+            if messages.contains(&UiMessage::Clicked(1)) {
+                transitions.push_back(ui::Transition::new(Duration::from_secs(1)).with_new_layout(
+                    ui::Layout {
+                        y_offset: layout.y_offset + 100.,
+                        ..layout
+                    },
+                ));
+            }
+        })
+        .build();
+    let mut harness = harness(vec![moving]).with_frame_time(Duration::from_millis(100));
+
+    let start = harness.layout().get(1).unwrap().outer.y;
+    harness.click(1);
+
+    // the element is half way after half the transition, clicks took two frames already
+    harness.advance(Duration::from_millis(300));
+    let y = harness.layout().get(1).unwrap().outer.y;
+    assert!((y - (start + 50.)).abs() < 0.01, "{} is not half way", y);
+
+    // completion messages are sent during the frame after the transition finished
+    let messages = harness.advance(Duration::from_millis(600));
+    assert!(messages.contains(&UiMessage::TransitionFinished(1)));
+    assert_eq!(harness.layout().get(1).unwrap().outer.y, start + 100.);
+
+    // the mouse is no longer above the moved element
+    harness.press_button(ggez::event::MouseButton::Left);
+    assert!(!harness.frame().contains(&UiMessage::Clicked(1)));
+}
+
+#[test]
+fn blocked_transition() {
+    let mut harness = harness(vec![button(1, "Fade").build()]);
+
+    // the second transition waits for the first one, as both change the visuals
+    let element = harness.root_mut().find_mut(1).unwrap();
+    element.add_transition(ui::Transition::new(Duration::from_secs(1)).with_new_opacity(0.5));
+    element.add_transition(
+        ui::Transition::new(Duration::ZERO)
+            .with_new_opacity(1.)
+            .with_new_transform(ui::UiTransform::new().with_translation(100., 0.)),
+    );
+    harness.frame();
+
+    // the waiting transition does not move the element yet, so it can still be clicked
+    assert!(harness.click(1).contains(&UiMessage::Clicked(1)));
+}
+
+#[test]
+fn crossfade_size() {
+    let mut harness =
+        harness(vec![button(1, "Hi").build()]).with_frame_time(Duration::from_millis(100));
+    let width = |harness: &ui::UiHarness<()>| harness.layout().get(1).unwrap().inner.w;
+    assert_eq!(width(&harness), 16.);
+
+    // leaf contents are measured once the cross-fade starts and blend their sizes
+    harness.root_mut().find_mut(1).unwrap().add_transition(
+        ui::Transition::new(Duration::from_secs(1))
+            .with_new_content(Text::new("Hello world"))
+            .with_crossfade(true),
+    );
+    harness.advance(Duration::from_millis(500));
+    let halfway = width(&harness);
+    assert!((halfway - 52.).abs() < 0.01, "{} is not half way", halfway);
+
+    // the element keeps the size of the new content
+    harness.advance(Duration::from_millis(600));
+    assert_eq!(width(&harness), 88.);
+    harness.frame();
+    assert_eq!(width(&harness), 88.);
+}
+
+#[test]
+fn scroll_box() {
+    let mut list = ui::containers::VerticalBox::new();
+    for id in 10..20 {
+        list.add(button(id, "Entry").build());
+    }
+    let list = ui::UiElementBuilder::new(2, list).build();
+    let scroll_box = ui::UiElementBuilder::new(1, ui::containers::ScrollBox::new(list))
+        .with_size(ui::Size::Fixed(200.), ui::Size::Fixed(100.))
+        .build();
+    let mut harness = harness(vec![scroll_box]);
+    // the scroll offset is the distance the list was moved up by
+    let scroll = |harness: &ui::UiHarness<()>| {
+        let layout = harness.layout();
+        layout.get(1).unwrap().inner.y - layout.get(2).unwrap().outer.y
+    };
+
+    // the mouse wheel only scrolls while the mouse is above the box
+    assert!(!harness.scroll(0., -1.));
+    harness.move_mouse_to(1);
+    harness.frame();
+    assert!(harness.scroll(0., -1.));
+    assert_eq!(scroll(&harness), 20.);
+    assert!(harness
+        .frame()
+        .iter()
+        .any(|message| matches!(message, UiMessage::Scrolled(_, -1))));
+
+    // dragging the scrollbar thumb to the bottom of the track
+    let bounds = harness.layout().get(1).unwrap().inner;
+    let max_scroll = harness.layout().get(2).unwrap().outer.h - bounds.h;
+    assert!(max_scroll > 20.);
+    harness.move_mouse((bounds.right() - 2., bounds.top() + 10.));
+    harness.press_button(ggez::event::MouseButton::Left);
+    harness.frame();
+    harness.move_mouse((bounds.right() - 2., bounds.bottom() + 50.));
+    harness.frame();
+    assert_eq!(scroll(&harness), max_scroll);
+
+    // releasing the button ends the drag
+    harness.release_button(ggez::event::MouseButton::Left);
+    harness.frame();
+    harness.move_mouse((bounds.right() - 2., bounds.top()));
+    harness.frame();
+    assert_eq!(scroll(&harness), max_scroll);
+}
+
+#[test]
+fn mutable_handler() {
+    let mut clicks = 0;
+    let counter = button(1, "0")
+        .with_message_handler_mut(move |messages, context| {
+            if messages.contains(&UiMessage::Clicked(1)) {
+                clicks += 1;
+                if let Some(text) = context.content_as_mut::<Text>() {
+                    *text = Text::new(clicks.to_string());
+                }
+                if clicks == 3 {
+                    context.emit(UiMessage::Extern(()));
+                }
+            }
+        })
+        .build();
+    let mut harness = harness(vec![counter]).with_frame_time(Duration::from_secs(1));
+
+    harness.click(1);
+    harness.click(1);
+    let messages = harness.click(1);
+    let text = harness
+        .root()
+        .find(1)
+        .unwrap()
+        .content_as::<Text>()
+        .unwrap();
+    assert_eq!(text.contents(), "3");
+
+    // emitted messages are returned as well
+    assert!(messages.contains(&UiMessage::Extern(())));
+}
+
+#[test]
+fn emitted_messages() {
+    let reset = button(1, "Reset")
+        .with_message_handler_mut(|messages, context| {
+            if messages.contains(&UiMessage::Clicked(1)) {
+                context.emit(UiMessage::Extern(()));
+            }
+        })
+        .build();
+    let label = button(2, "Dirty")
+        .with_message_handler_mut(|messages, context| {
+            if messages.contains(&UiMessage::Extern(())) {
+                context.set_content(Text::new("Clean"));
+            }
+        })
+        .build();
+    // a handler that keeps emitting new messages in response to its own
+    let endless = button(3, "Endless")
+        .with_message_handler_mut(|messages, context| {
+            for message in messages {
+                if let UiMessage::Scrolled(3, steps) = message {
+                    context.emit(UiMessage::Scrolled(3, steps + 1));
+                }
+            }
+        })
+        .build();
+    let mut harness = harness(vec![reset, label, endless]);
+
+    // the label reacts within the same frame the button is pressed in
+    harness.move_mouse_to(1);
+    harness.press_button(ggez::event::MouseButton::Left);
+    let messages = harness.frame();
+    assert!(messages.contains(&UiMessage::Extern(())));
+    let text = harness
+        .root()
+        .find(2)
+        .unwrap()
+        .content_as::<Text>()
+        .unwrap();
+    assert_eq!(text.contents(), "Clean");
+
+    // endless chains of messages are cut off and continued next frame
+    harness.send(UiMessage::Scrolled(3, 0));
+    let messages = harness.frame();
+    assert!(messages.contains(&UiMessage::Scrolled(3, 16)));
+    assert!(!messages.contains(&UiMessage::Scrolled(3, 17)));
+    assert!(harness.frame().contains(&UiMessage::Scrolled(3, 17)));
+}
+
+/// A minimal checkbox widget, toggled by pressing Enter or Space while focused.
+struct Checkbox {
+    /// Wether the box is checked.
+    checked: bool,
+    /// Wether the box was toggled since the last message collection.
+    changed: bool,
+}
+
+impl ui::UiContent<()> for Checkbox {
+    fn draw_content(
+        &mut self,
+        _ctx: &mut ggez::Context,
+        _canvas: &mut ggez::graphics::Canvas,
+        _param: ui::UiDrawParam,
+    ) {
+    }
+
+    fn focus_triggered(&mut self) {
+        self.checked = !self.checked;
+        self.changed = true;
+    }
+
+    fn collect_messages(&mut self, id: u32, messages: &mut HashSet<UiMessage<()>>) {
+        if self.changed {
+            messages.insert(UiMessage::ValueChanged(id, ui::UiValue::Bool(self.checked)));
+            self.changed = false;
+        }
+    }
+
+    fn value(&self) -> Option<ui::UiValue> {
+        Some(ui::UiValue::Bool(self.checked))
+    }
+}
+
+#[test]
+fn values() {
+    let checkbox = ui::UiElementBuilder::new(
+        1,
+        Checkbox {
+            checked: false,
+            changed: false,
+        },
+    )
+    .with_size(ui::Size::Fixed(20.), ui::Size::Fixed(20.))
+    .with_focusable(true)
+    .build();
+    let input = ui::UiElementBuilder::new(2, ui::basic::TextInput::new().with_text("Name")).build();
+    let mut harness = harness(vec![checkbox, input]);
+
+    assert_eq!(harness.root().get_value(1), Some(ui::UiValue::Bool(false)));
+    assert_eq!(
+        harness.root().get_value(2),
+        Some(ui::UiValue::Text("Name".to_owned()))
+    );
+    assert_eq!(harness.root().get_value(3), None);
+
+    // toggling the focused checkbox
+    harness.tap_key(VirtualKeyCode::Tab);
+    let messages = harness.tap_key(VirtualKeyCode::Space);
+    assert!(messages.contains(&UiMessage::ValueChanged(1, ui::UiValue::Bool(true))));
+    assert_eq!(harness.root().get_value(1), Some(ui::UiValue::Bool(true)));
+
+    // floats are compared bitwise
+    let mut set = HashSet::new();
+    set.insert(UiMessage::<()>::ValueChanged(1, ui::UiValue::Float(0.5)));
+    assert!(set.contains(&UiMessage::ValueChanged(1, ui::UiValue::Float(0.5))));
+    assert!(!set.contains(&UiMessage::ValueChanged(1, ui::UiValue::Float(-0.5))));
+}
+
+/// Returns the text currently entered into the text field with the passed ID.
+fn text_of(harness: &ui::UiHarness<()>, id: u32) -> String {
+    harness.root().get_text(id).unwrap_or_default().to_owned()
+}
+
+#[test]
+fn text_input() {
+    let input = ui::basic::TextInput::new()
+        .with_placeholder("Enter your name here")
+        .with_max_length(8);
+    let mut harness = harness(vec![
+        ui::UiElementBuilder::new(1, input).build(),
+        button(2, "Other").build(),
+    ]);
+
+    // the placeholder is neither text nor value, but the field is wide enough to display it
+    assert_eq!(text_of(&harness, 1), "");
+    assert_eq!(
+        harness.root().get_value(1),
+        Some(ui::UiValue::Text(String::new()))
+    );
+    assert!(harness.layout().get(1).unwrap().inner.w >= 20. * 8.);
+
+    // inactive fields ignore typing
+    assert!(!harness.type_text("Hi"));
+    assert!(!harness.press_key(VirtualKeyCode::Back));
+    assert!(harness.frame().is_empty());
+
+    // clicking activates the field
+    harness.click(1);
+    assert!(harness.type_text("Hello"));
+    let messages = harness.frame();
+    assert!(messages.contains(&UiMessage::TextChanged(1)));
+    assert!(messages.contains(&UiMessage::ValueChanged(
+        1,
+        ui::UiValue::Text("Hello".to_owned())
+    )));
+
+    // home and end move the caret, typing stops at the maximum length
+    assert!(!harness
+        .tap_key(VirtualKeyCode::Home)
+        .contains(&UiMessage::TextChanged(1)));
+    harness.type_text("Oh, hi!");
+    assert_eq!(text_of(&harness, 1), "Oh,Hello");
+    harness.tap_key(VirtualKeyCode::End);
+    harness.type_text("!");
+    assert_eq!(text_of(&harness, 1), "Oh,Hello");
+    assert!(harness.frame().is_empty());
+
+    // backspace deletes before, delete after the caret
+    assert!(harness
+        .tap_key(VirtualKeyCode::Back)
+        .contains(&UiMessage::TextChanged(1)));
+    harness.tap_key(VirtualKeyCode::Home);
+    harness.tap_key(VirtualKeyCode::Delete);
+    assert_eq!(text_of(&harness, 1), "h,Hell");
+    assert!(harness.type_text("Bye"));
+    assert_eq!(text_of(&harness, 1), "Byh,Hell");
+    assert!(harness.frame().contains(&UiMessage::TextChanged(1)));
+
+    // keys the field does not handle are passed on
+    assert!(!harness.press_key(VirtualKeyCode::F1));
+    harness.release_key(VirtualKeyCode::F1);
+
+    // enter submits the text
+    let messages = harness.tap_key(VirtualKeyCode::Return);
+    assert!(messages.contains(&UiMessage::TextSubmitted(1)));
+    assert!(!messages.contains(&UiMessage::TextChanged(1)));
+
+    // clicking somewhere else deactivates the field
+    harness.click(2);
+    assert!(!harness.type_text("x"));
+    assert_eq!(text_of(&harness, 1), "Byh,Hell");
+}
+
+#[test]
+fn text_input_builder() {
+    // limits apply to the text no matter the order of the builder calls
+    let input = ui::basic::TextInput::new()
+        .with_text("one\ntwo")
+        .with_multiline(3);
+    assert_eq!(input.get_text(), "one\ntwo");
+    let input = ui::basic::TextInput::new()
+        .with_multiline(3)
+        .with_text("one\ntwo");
+    assert_eq!(input.get_text(), "one\ntwo");
+    let input = ui::basic::TextInput::new().with_text("one\ntwo");
+    assert_eq!(input.get_text(), "one two");
+
+    let input = ui::basic::TextInput::new()
+        .with_text("Too long")
+        .with_max_length(3);
+    assert_eq!(input.get_text(), "Too");
+    let input = ui::basic::TextInput::new()
+        .with_max_length(3)
+        .with_text("Too long");
+    assert_eq!(input.get_text(), "Too");
+
+    // setting the text later applies the limits as well
+    let mut input = ui::basic::TextInput::new().with_max_length(5);
+    input.set_text("a\nbcdefg");
+    assert_eq!(input.get_text(), "a bcd");
+}
@@ -0,0 +1,45 @@
+//! Headless layout tests. These compute layouts without a window and compare them against the snapshots in ```tests/snapshots```.
+//! Run ```MOOEYE_UPDATE_SNAPSHOTS=1 cargo test --test layout``` to update the snapshots after intended layout changes.
+
+use ggez::graphics::{Rect, Text};
+use mooeye::ui::{self, UiContainer};
+
+/// Creates a text element without a context. Its size is set by measuring the tree later.
+fn text(id: u32, text: &str) -> ui::UiElement<()> {
+    ui::UiElementBuilder::new(id, Text::new(text)).build()
+}
+
+#[test]
+fn containers() {
+    let mut vbox = ui::containers::VerticalBox::new_spaced(10.);
+    vbox.add(text(1, "Title"));
+
+    let mut hbox = ui::containers::HorizontalBox::new();
+    hbox.add(text(2, "Left"));
+    hbox.add(text(3, "Right\nside"));
+    vbox.add(ui::UiElementBuilder::new(4, hbox).as_shrink().build());
+
+    let mut grid = ui::containers::GridBox::new(2, 2);
+    grid.add(text(5, "A"), 0, 0).unwrap();
+    grid.add(text(6, "Longer"), 1, 1).unwrap();
+    vbox.add(ui::UiElementBuilder::new(7, grid).as_shrink().build());
+
+    let mut gui = ui::UiElementBuilder::new(0, vbox).as_shrink().build();
+    gui.measure(&ui::MonospaceMeasure::default());
+
+    gui.compute_layout(Rect::new(0., 0., 800., 600.))
+        .assert_snapshot("tests/snapshots/containers.snap");
+}
+
+#[test]
+fn too_small() {
+    let mut vbox = ui::containers::VerticalBox::new();
+    vbox.add(text(1, "Fits"));
+    vbox.add(text(2, "Much too long to fit"));
+
+    let mut gui = ui::UiElementBuilder::new(0, vbox).as_shrink().build();
+    gui.measure(&ui::MonospaceMeasure::default());
+
+    gui.compute_layout(Rect::new(0., 0., 100., 100.))
+        .assert_snapshot("tests/snapshots/too_small.snap");
+}